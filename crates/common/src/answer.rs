@@ -0,0 +1,160 @@
+use std::{fmt, num::TryFromIntError};
+
+/// The result of solving a part, formatted by the caller.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Answer {
+    Integer(i128),
+    Text(String),
+    /// Multi-line output that must be read by eye, like a CRT screen.
+    Art(Vec<String>),
+    Unsolved,
+}
+
+impl Answer {
+    pub fn is_multi_line(&self) -> bool {
+        matches!(self, Answer::Art(_))
+    }
+
+    pub fn to_json(&self) -> String {
+        match self {
+            Answer::Integer(value) => value.to_string(),
+            Answer::Text(value) => json_string(value),
+            Answer::Art(rows) => format!(
+                "[{}]",
+                rows.iter()
+                    .map(|row| json_string(row))
+                    .collect::<Vec<_>>()
+                    .join(",")
+            ),
+            Answer::Unsolved => String::from("null"),
+        }
+    }
+}
+
+fn json_string(value: &str) -> String {
+    let mut json = String::with_capacity(value.len() + 2);
+
+    json.push('"');
+
+    for c in value.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if c.is_control() => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c),
+        }
+    }
+
+    json.push('"');
+    json
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(value) => write!(f, "{}", value),
+            Answer::Text(value) => write!(f, "{}", value),
+            Answer::Art(rows) => write!(f, "{}", rows.join("\n")),
+            Answer::Unsolved => write!(f, "unsolved"),
+        }
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for Answer {
+                fn from(value: $ty) -> Self {
+                    Answer::Integer(i128::from(value))
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(i32, u32, i64, u64, i128);
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        // usize is at most 64 bits on every supported target
+        Answer::Integer(i128::try_from(value).expect("usize answer exceeds i128"))
+    }
+}
+
+/// The widest products, such as a monkey business over billions of rounds,
+/// can still exceed `i128`, so converting fails rather than wrapping.
+impl TryFrom<u128> for Answer {
+    type Error = TryFromIntError;
+
+    fn try_from(value: u128) -> Result<Self, Self::Error> {
+        i128::try_from(value).map(Answer::Integer)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_owned())
+    }
+}
+
+impl From<Vec<String>> for Answer {
+    fn from(rows: Vec<String>) -> Self {
+        Answer::Art(rows)
+    }
+}
+
+impl<const N: usize> From<[&str; N]> for Answer {
+    fn from(rows: [&str; N]) -> Self {
+        Answer::Art(rows.iter().map(|row| row.to_string()).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn displays() {
+        assert_eq!(Answer::from(13140).to_string(), "13140");
+        assert_eq!(Answer::from("CMZ").to_string(), "CMZ");
+        assert_eq!(
+            Answer::from(vec![String::from("#.#"), String::from(".#.")]).to_string(),
+            "#.#\n.#."
+        );
+        assert_eq!(
+            Answer::from(["#.#", ".#."]),
+            Answer::from(vec![String::from("#.#"), String::from(".#.")])
+        );
+        assert_eq!(Answer::Unsolved.to_string(), "unsolved");
+    }
+
+    #[test]
+    fn keeps_large_integers() {
+        assert_eq!(Answer::from(u64::MAX).to_string(), "18446744073709551615");
+        assert_eq!(
+            Answer::try_from(27_065_198_186_617_228_394_u128),
+            Ok(Answer::Integer(27_065_198_186_617_228_394))
+        );
+        assert!(Answer::try_from(u128::MAX).is_err());
+    }
+
+    #[test]
+    fn serializes_json() {
+        assert_eq!(Answer::from(-5).to_json(), "-5");
+        assert_eq!(Answer::from("a \"b\"\n").to_json(), "\"a \\\"b\\\"\\n\"");
+        assert_eq!(
+            Answer::from(vec![String::from("#."), String::from(".#")]).to_json(),
+            "[\"#.\",\".#\"]"
+        );
+        assert_eq!(Answer::Unsolved.to_json(), "null");
+    }
+}
@@ -0,0 +1,170 @@
+//! Command line handling shared by the `aoc` runner and the binaries within
+//! day crates, which take flags and at most one positional argument,
+//! usually an input file or `-` for stdin.
+
+use std::{
+    fmt, fs,
+    io::{self, Read},
+    process,
+    str::FromStr,
+};
+
+/// Reads the input file, or stdin when the path is `-`.
+pub fn read_input(path: &str) -> Result<String, String> {
+    let result = if path == "-" {
+        let mut buffer = String::new();
+
+        io::stdin().read_to_string(&mut buffer).map(|_| buffer)
+    } else {
+        fs::read_to_string(path)
+    };
+
+    result.map_err(|error| format!("Unable to read input {}: {}", path, error))
+}
+
+/// Prints the error and exits unsuccessfully.
+pub fn fail<E: fmt::Display>(error: E) -> ! {
+    eprintln!("{}", error);
+    process::exit(1);
+}
+
+/// Prints the error followed by the usage, and exits unsuccessfully.
+pub fn fail_with_usage<E: fmt::Display>(error: E, usage: &str) -> ! {
+    eprintln!("{}", error);
+    eprintln!("{}", usage);
+    process::exit(1);
+}
+
+/// Flags and their values, collecting the single positional input path.
+pub struct Args<I> {
+    args: I,
+    input: Option<String>,
+}
+
+impl<I: Iterator<Item = String>> Args<I> {
+    pub fn new(args: I) -> Self {
+        Args { args, input: None }
+    }
+
+    /// The next flag to handle, or `None` when every argument is consumed.
+    pub fn next_flag(&mut self) -> Result<Option<String>, String> {
+        for arg in self.args.by_ref() {
+            if arg.starts_with('-') && arg != "-" {
+                return Ok(Some(arg));
+            }
+
+            if self.input.is_some() {
+                return Err(unknown_argument(&arg));
+            }
+
+            self.input = Some(arg);
+        }
+
+        Ok(None)
+    }
+
+    /// The value following a flag.
+    pub fn value(&mut self, flag: &str) -> Result<String, String> {
+        self.args
+            .next()
+            .ok_or_else(|| format!("Missing value for {}", flag))
+    }
+
+    pub fn parse<T: FromStr>(&mut self, flag: &str) -> Result<T, String> {
+        self.parse_where(flag, |_| true)
+    }
+
+    /// Parses the value following a flag, which must also be `valid`.
+    pub fn parse_where<T: FromStr>(
+        &mut self,
+        flag: &str,
+        valid: impl FnOnce(&T) -> bool,
+    ) -> Result<T, String> {
+        let value = self.value(flag)?;
+
+        value
+            .parse()
+            .ok()
+            .filter(valid)
+            .ok_or_else(|| format!("Invalid value for {}: {}", flag, value))
+    }
+
+    /// The input path, once every flag has been handled.
+    pub fn input(self) -> Result<String, String> {
+        self.input.ok_or_else(|| String::from("Missing input file"))
+    }
+
+    /// Checks nothing positional was given, for commands taking only flags.
+    pub fn no_input(self) -> Result<(), String> {
+        match self.input {
+            Some(arg) => Err(unknown_argument(&arg)),
+            None => Ok(()),
+        }
+    }
+}
+
+pub fn unknown_argument(arg: &str) -> String {
+    format!("Unknown argument {}", arg)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(line: &str) -> Args<impl Iterator<Item = String>> {
+        Args::new(
+            line.split_whitespace()
+                .map(String::from)
+                .collect::<Vec<_>>()
+                .into_iter(),
+        )
+    }
+
+    #[test]
+    fn parses_flags_and_input() {
+        let mut args = args("--rounds 20 input.txt --relief 3");
+
+        assert_eq!(args.next_flag(), Ok(Some(String::from("--rounds"))));
+        assert_eq!(args.parse::<usize>("--rounds"), Ok(20));
+        assert_eq!(args.next_flag(), Ok(Some(String::from("--relief"))));
+        assert_eq!(
+            args.parse_where::<u64>("--relief", |relief| *relief > 3),
+            Err(String::from("Invalid value for --relief: 3"))
+        );
+        assert_eq!(args.next_flag(), Ok(None));
+        assert_eq!(args.input(), Ok(String::from("input.txt")));
+    }
+
+    #[test]
+    fn rejects_arguments() {
+        let mut stdin = args("-");
+
+        assert_eq!(stdin.next_flag(), Ok(None));
+        assert_eq!(stdin.input(), Ok(String::from("-")));
+
+        let mut extra = args("a.txt b.txt");
+
+        assert_eq!(
+            extra.next_flag(),
+            Err(String::from("Unknown argument b.txt"))
+        );
+
+        let mut missing = args("--knots");
+
+        assert_eq!(missing.next_flag(), Ok(Some(String::from("--knots"))));
+        assert_eq!(
+            missing.value("--knots"),
+            Err(String::from("Missing value for --knots"))
+        );
+        assert_eq!(args("").input(), Err(String::from("Missing input file")));
+        assert_eq!(args("--json").no_input(), Ok(()));
+
+        let mut stray = args("a.txt");
+
+        assert_eq!(stray.next_flag(), Ok(None));
+        assert_eq!(
+            stray.no_input(),
+            Err(String::from("Unknown argument a.txt"))
+        );
+    }
+}
@@ -0,0 +1,198 @@
+use std::{error::Error, fmt};
+
+/// Malformed puzzle input, optionally located to a line and column (both 1-based).
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParseError {
+    pub message: String,
+    pub text: String,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+}
+
+impl ParseError {
+    pub fn new<M: Into<String>, T: Into<String>>(message: M, text: T) -> Self {
+        ParseError {
+            message: message.into(),
+            text: text.into(),
+            line: None,
+            column: None,
+        }
+    }
+
+    /// Creates an error located by the offending slice, which must borrow from `input`.
+    pub fn at<M: Into<String>>(input: &str, text: &str, message: M) -> Self {
+        ParseError::new(message, text).locate(input, text)
+    }
+
+    /// Locates the error within `input` using `span`, a slice of `input` that
+    /// contains the offending text. Errors that already have a location are
+    /// left untouched, so this can be applied at every level of parsing.
+    pub fn locate(mut self, input: &str, span: &str) -> Self {
+        if self.line.is_some() {
+            return self;
+        }
+
+        let start = input.as_ptr() as usize;
+        let offset = span.as_ptr() as usize;
+
+        if offset < start || offset + span.len() > start + input.len() {
+            return self;
+        }
+
+        let mut offset = offset - start;
+
+        if !self.text.is_empty() {
+            if let Some(index) = span.find(self.text.as_str()) {
+                offset += index;
+            }
+        }
+
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map(|index| index + 1).unwrap_or(0);
+
+        self.line = Some(before.matches('\n').count() + 1);
+        self.column = Some(before[line_start..].chars().count() + 1);
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.line, self.column) {
+            (Some(line), Some(column)) if column > 1 => {
+                write!(f, "line {}, column {}: ", line, column)?;
+            }
+            (Some(line), _) => {
+                write!(f, "line {}: ", line)?;
+            }
+            _ => {}
+        }
+
+        write!(f, "{}", self.message)?;
+
+        if !self.text.is_empty() {
+            write!(f, ", found {:?}", self.text)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+/// A failure answering a part from input that parsed, such as a value
+/// overflowing or the input having no answer.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SolveError {
+    pub message: String,
+}
+
+impl SolveError {
+    pub fn new<M: Into<String>>(message: M) -> Self {
+        SolveError {
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for SolveError {}
+
+/// Anything that stops a [`Solver`](crate::Solver) answering a part.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SolverError {
+    Parse(ParseError),
+    Solve(SolveError),
+    UnknownPart(u8),
+}
+
+impl From<ParseError> for SolverError {
+    fn from(error: ParseError) -> Self {
+        SolverError::Parse(error)
+    }
+}
+
+impl From<SolveError> for SolverError {
+    fn from(error: SolveError) -> Self {
+        SolverError::Solve(error)
+    }
+}
+
+impl fmt::Display for SolverError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SolverError::Parse(error) => write!(f, "{}", error),
+            SolverError::Solve(error) => write!(f, "{}", error),
+            SolverError::UnknownPart(part) => {
+                write!(f, "unknown part {}, expected 1 or 2", part)
+            }
+        }
+    }
+}
+
+impl Error for SolverError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn displays() {
+        assert_eq!(
+            ParseError::new("no marker found", "").to_string(),
+            "no marker found"
+        );
+        assert_eq!(
+            ParseError::new("expected a direction", "X").to_string(),
+            "expected a direction, found \"X\""
+        );
+    }
+
+    #[test]
+    fn locates_lines() {
+        let input = "move 1 from 2 to 3\nmvoe 1 from 2 to 3\n";
+        let line = input.lines().nth(1).unwrap();
+        let error = ParseError::at(input, line, "expected \"move N from A to B\"");
+
+        assert_eq!(error.line, Some(2));
+        assert_eq!(error.column, Some(1));
+        assert_eq!(
+            error.to_string(),
+            "line 2: expected \"move N from A to B\", found \"mvoe 1 from 2 to 3\""
+        );
+    }
+
+    #[test]
+    fn locates_columns_within_span() {
+        let input = "R 4\nU x\n";
+        let line = input.lines().nth(1).unwrap();
+        let error = ParseError::new("expected a step count", "x").locate(input, line);
+
+        assert_eq!(error.line, Some(2));
+        assert_eq!(error.column, Some(3));
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 3: expected a step count, found \"x\""
+        );
+    }
+
+    #[test]
+    fn keeps_first_location() {
+        let input = "a\nb\n";
+        let error = ParseError::at(input, &input[2..3], "bad").locate(input, &input[0..1]);
+
+        assert_eq!(error.line, Some(2));
+    }
+
+    #[test]
+    fn ignores_foreign_spans() {
+        let error = ParseError::new("bad", "x").locate("input", "other");
+
+        assert_eq!(error.line, None);
+    }
+}
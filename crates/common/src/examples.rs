@@ -0,0 +1,55 @@
+use crate::{answer::Answer, solution::Solver};
+
+/// Directory of example fixtures, relative to the workspace root.
+pub const EXAMPLES_DIR: &str = "examples";
+
+/// Asserts the answer of a fixture for a part named `part_1` or `part_2`.
+/// Used by [`examples!`], which names its tests after the parts.
+pub fn check_example(solution: &dyn Solver, input: &str, part: &str, expected: impl Into<Answer>) {
+    let part = match part {
+        "part_1" => 1,
+        "part_2" => 2,
+        _ => panic!("Unknown part {}, expected part_1 or part_2", part),
+    };
+
+    assert_eq!(solution.solve(input, part), Ok(expected.into()));
+}
+
+/// Generates a module per fixture in `examples/<dir>/<name>.txt`, containing
+/// a `#[test]` for every part with an expected answer.
+///
+/// ```ignore
+/// aoc_common::examples!(Day1, "day-1", {
+///     example: { part_1: 24000, part_2: 45000 },
+///     single_elf: { part_1: 100 },
+/// });
+/// ```
+#[macro_export]
+macro_rules! examples {
+    ($solution:expr, $dir:literal, {
+        $($name:ident: { $($part:ident: $expected:expr),* $(,)? }),* $(,)?
+    }) => {
+        $(
+            mod $name {
+                use super::*;
+
+                const INPUT: &str = include_str!(concat!(
+                    env!("CARGO_MANIFEST_DIR"),
+                    "/../../",
+                    "examples/",
+                    $dir,
+                    "/",
+                    stringify!($name),
+                    ".txt"
+                ));
+
+                $(
+                    #[test]
+                    fn $part() {
+                        $crate::check_example(&$solution, INPUT, stringify!($part), $expected);
+                    }
+                )*
+            }
+        )*
+    };
+}
@@ -0,0 +1,352 @@
+use crate::{direction::Direction, error::ParseError, parse, point::Point};
+use std::ops::{Index, IndexMut};
+
+/// Where a ray cast by [`Grid::cast`] stopped.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Cast {
+    /// A cell matched the predicate, `distance` steps from the start.
+    Hit { point: Point, distance: usize },
+    /// The edge of the grid was passed after `distance` steps within it.
+    Edge { distance: usize },
+}
+
+impl Cast {
+    pub fn distance(&self) -> usize {
+        match self {
+            Cast::Hit { distance, .. } | Cast::Edge { distance } => *distance,
+        }
+    }
+
+    pub fn is_hit(&self) -> bool {
+        matches!(self, Cast::Hit { .. })
+    }
+}
+
+/// A rectangular 2D grid of cells stored in row-major order.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(
+            cells.len(),
+            width * height,
+            "Grid cells do not match its dimensions."
+        );
+
+        Grid {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid::new(width, height, vec![value; width * height])
+    }
+
+    /// Creates a grid from non-blank lines of text, mapping each character
+    /// to a cell. Errors when a character can't be mapped to a cell, or when
+    /// the lines are not all the same length.
+    pub fn try_parse<F>(input: &str, mut map: F) -> Result<Self, ParseError>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        let mut cells = vec![];
+        let mut width = 0;
+        let mut height = 0;
+
+        for line in parse::lines(input) {
+            let line = line.trim_end();
+            let count = cells.len();
+
+            for (index, c) in line.char_indices() {
+                match map(c) {
+                    Some(cell) => cells.push(cell),
+                    None => {
+                        return Err(ParseError::at(
+                            input,
+                            &line[index..index + c.len_utf8()],
+                            "unexpected character",
+                        ));
+                    }
+                }
+            }
+
+            if height == 0 {
+                width = cells.len();
+            } else if cells.len() - count != width {
+                return Err(ParseError::at(
+                    input,
+                    line,
+                    format!("expected a row of {} cells", width),
+                ));
+            }
+
+            height += 1;
+        }
+
+        Ok(Grid::new(width, height, cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        point.x >= 0
+            && point.y >= 0
+            && (point.x as usize) < self.width
+            && (point.y as usize) < self.height
+    }
+
+    pub fn is_edge(&self, point: Point) -> bool {
+        self.contains(point)
+            && (point.x == 0
+                || point.y == 0
+                || point.x as usize == self.width - 1
+                || point.y as usize == self.height - 1)
+    }
+
+    fn offset(&self, point: Point) -> Option<usize> {
+        if self.contains(point) {
+            Some(point.y as usize * self.width + point.x as usize)
+        } else {
+            None
+        }
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.offset(point).map(|offset| &self.cells[offset])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.offset(point).map(|offset| &mut self.cells[offset])
+    }
+
+    /// The adjacent point in the direction, if it's within the grid.
+    pub fn step(&self, point: Point, direction: Direction) -> Option<Point> {
+        let next = point + direction.step();
+
+        if self.contains(next) {
+            Some(next)
+        } else {
+            None
+        }
+    }
+
+    /// A new grid of the same size, with each cell mapped from this one.
+    pub fn map<U, F>(&self, mut map: F) -> Grid<U>
+    where
+        F: FnMut(Point, &T) -> U,
+    {
+        Grid::new(
+            self.width,
+            self.height,
+            self.iter().map(|(point, cell)| map(point, cell)).collect(),
+        )
+    }
+
+    /// Every point in the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x as i32, y as i32)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Orthogonally adjacent points that are within the grid.
+    pub fn neighbours(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        point
+            .neighbours()
+            .into_iter()
+            .filter(|neighbour| self.contains(*neighbour))
+    }
+
+    /// Cells walked from (but excluding) the starting point, moving by
+    /// `step` each time, until the edge of the grid is passed.
+    pub fn ray(&self, from: Point, step: Point) -> impl Iterator<Item = (Point, &T)> {
+        let mut current = from;
+
+        std::iter::from_fn(move || {
+            if step == Point::ORIGIN {
+                return None;
+            }
+
+            current += step;
+
+            self.get(current).map(|cell| (current, cell))
+        })
+    }
+
+    /// Cells walked from (but excluding) the starting point in a direction.
+    pub fn walk(&self, from: Point, direction: Direction) -> impl Iterator<Item = (Point, &T)> {
+        self.ray(from, direction.step())
+    }
+
+    /// Walks from (but excluding) the starting point in a direction until
+    /// a cell matches the predicate, or the edge of the grid is passed.
+    pub fn cast<F>(&self, from: Point, direction: Direction, mut stop: F) -> Cast
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let mut distance = 0;
+
+        for (point, cell) in self.walk(from, direction) {
+            distance += 1;
+
+            if stop(cell) {
+                return Cast::Hit { point, distance };
+            }
+        }
+
+        Cast::Edge { distance }
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point)
+            .unwrap_or_else(|| panic!("Point {} is outside of the grid.", point))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("Point {} is outside of the grid.", point))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_grid() -> Grid<u32> {
+        Grid::try_parse("123\n456\n789\n", |c| c.to_digit(10)).unwrap()
+    }
+
+    #[test]
+    fn parses() {
+        let grid = create_grid();
+
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 3);
+        assert_eq!(grid[Point::new(0, 0)], 1);
+        assert_eq!(grid[Point::new(2, 1)], 6);
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.get(Point::new(0, -1)), None);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let error = Grid::try_parse("12\n3x\n", |c| c.to_digit(10)).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 2: unexpected character, found \"x\""
+        );
+
+        let error = Grid::try_parse("12\n345\n", |c| c.to_digit(10)).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "line 2: expected a row of 2 cells, found \"345\""
+        );
+    }
+
+    #[test]
+    fn edges() {
+        let grid = create_grid();
+
+        assert!(grid.is_edge(Point::new(0, 1)));
+        assert!(grid.is_edge(Point::new(2, 2)));
+        assert!(!grid.is_edge(Point::new(1, 1)));
+        assert!(!grid.is_edge(Point::new(5, 5)));
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = create_grid();
+
+        assert_eq!(
+            grid.neighbours(Point::new(0, 0)).collect::<Vec<_>>(),
+            vec![Point::new(1, 0), Point::new(0, 1)]
+        );
+        assert_eq!(grid.neighbours(Point::new(1, 1)).count(), 4);
+    }
+
+    #[test]
+    fn rays() {
+        let grid = create_grid();
+
+        assert_eq!(
+            grid.ray(Point::new(0, 1), Point::new(1, 0))
+                .map(|(_, cell)| *cell)
+                .collect::<Vec<_>>(),
+            vec![5, 6]
+        );
+        assert_eq!(
+            grid.ray(Point::new(2, 2), Point::new(-1, -1))
+                .map(|(_, cell)| *cell)
+                .collect::<Vec<_>>(),
+            vec![5, 1]
+        );
+        assert_eq!(grid.ray(Point::new(1, 0), Point::new(0, -1)).count(), 0);
+    }
+
+    #[test]
+    fn casts() {
+        let grid = create_grid();
+
+        assert_eq!(
+            grid.cast(Point::new(0, 0), Direction::Right, |cell| *cell > 3),
+            Cast::Edge { distance: 2 }
+        );
+        assert_eq!(
+            grid.cast(Point::new(0, 0), Direction::Down, |cell| *cell > 2),
+            Cast::Hit {
+                point: Point::new(0, 1),
+                distance: 1
+            }
+        );
+        assert_eq!(
+            grid.cast(Point::new(1, 0), Direction::Up, |_| true),
+            Cast::Edge { distance: 0 }
+        );
+        assert_eq!(grid.step(Point::new(1, 0), Direction::Up), None);
+        assert_eq!(
+            grid.step(Point::new(1, 0), Direction::Left),
+            Some(Point::new(0, 0))
+        );
+    }
+
+    #[test]
+    fn maps_cells() {
+        let grid = create_grid().map(|point, cell| point.x as u32 + cell);
+
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid[Point::new(2, 2)], 11);
+        assert_eq!(grid.rows().next(), Some(&[1, 3, 5][..]));
+    }
+}
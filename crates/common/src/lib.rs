@@ -0,0 +1,17 @@
+mod answer;
+pub mod cli;
+mod direction;
+mod error;
+mod examples;
+mod grid;
+pub mod parse;
+mod point;
+mod solution;
+
+pub use answer::*;
+pub use direction::*;
+pub use error::*;
+pub use examples::*;
+pub use grid::*;
+pub use point::*;
+pub use solution::*;
@@ -0,0 +1,97 @@
+use crate::error::ParseError;
+use std::str::FromStr;
+
+/// Non-blank lines of the input.
+pub fn lines(input: &str) -> impl Iterator<Item = &str> {
+    input.lines().filter(|line| !line.trim().is_empty())
+}
+
+/// Chunks of the input separated by blank lines. Leading whitespace
+/// within a block is preserved, as some puzzles draw diagrams with it.
+pub fn blocks(input: &str) -> impl Iterator<Item = &str> {
+    input
+        .trim_end()
+        .split("\n\n")
+        .map(|block| block.trim_matches('\n'))
+        .filter(|block| !block.trim().is_empty())
+}
+
+/// Every integer within the text, in order. A `-` is only treated as a sign
+/// when it isn't preceded by a digit, so ranges like `2-4` yield `2` and `4`.
+/// Errors if a number does not fit in `T`.
+pub fn integers<T: FromStr>(text: &str) -> Result<Vec<T>, ParseError> {
+    let bytes = text.as_bytes();
+    let mut numbers = vec![];
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let start = i;
+
+        if bytes[i] == b'-'
+            && bytes.get(i + 1).is_some_and(u8::is_ascii_digit)
+            && (i == 0 || !bytes[i - 1].is_ascii_digit())
+        {
+            i += 1;
+        }
+
+        if i < bytes.len() && bytes[i].is_ascii_digit() {
+            while i < bytes.len() && bytes[i].is_ascii_digit() {
+                i += 1;
+            }
+
+            let number = &text[start..i];
+
+            numbers.push(
+                number
+                    .parse()
+                    .map_err(|_| ParseError::at(text, number, "integer out of range"))?,
+            );
+        } else {
+            i = start + 1;
+        }
+    }
+
+    Ok(numbers)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn skips_blank_lines() {
+        assert_eq!(
+            lines("a\n\nb\n  \nc\n").collect::<Vec<_>>(),
+            vec!["a", "b", "c"]
+        );
+    }
+
+    #[test]
+    fn splits_blocks() {
+        assert_eq!(
+            blocks("1\n2\n\n3\n\n\n4\n").collect::<Vec<_>>(),
+            vec!["1\n2", "3", "4"]
+        );
+        assert_eq!(
+            blocks("    [D]\n[N] [C]\n\nmove 1").collect::<Vec<_>>(),
+            vec!["    [D]\n[N] [C]", "move 1"]
+        );
+    }
+
+    #[test]
+    fn extracts_integers() {
+        assert_eq!(integers::<u8>("move 3 from 1 to 12"), Ok(vec![3, 1, 12]));
+        assert_eq!(integers::<u8>("2-4,6-8"), Ok(vec![2, 4, 6, 8]));
+        assert_eq!(integers::<i32>("addx -11"), Ok(vec![-11]));
+        assert_eq!(integers::<i32>("x=-3, y=4-"), Ok(vec![-3, 4]));
+        assert_eq!(integers::<u32>("no numbers"), Ok(vec![]));
+    }
+
+    #[test]
+    fn errors_on_overflow() {
+        let error = integers::<u8>("move 300 from 1 to 2").unwrap_err();
+
+        assert_eq!(error.text, "300");
+        assert_eq!(error.column, Some(6));
+    }
+}
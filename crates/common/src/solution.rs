@@ -0,0 +1,126 @@
+use crate::{
+    answer::Answer,
+    error::{ParseError, SolveError, SolverError},
+};
+use std::time::{Duration, Instant};
+
+/// A single day of the calendar. Each day crate exposes a unit struct
+/// implementing this trait. Parsing is separate from solving, so a runner
+/// can parse once for both parts and time each step on its own.
+pub trait Solution {
+    type Input<'a>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError>;
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Result<Answer, SolveError>;
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Result<Answer, SolveError>;
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Timing {
+    pub answer: Answer,
+    pub parse: Duration,
+    pub solve: Duration,
+}
+
+/// An object safe view of [`Solution`], so that days with different
+/// input types can be registered and dispatched to together.
+pub trait Solver {
+    /// Parses the input once and answers each of `parts` with it. Every
+    /// timing shares the one parse duration.
+    fn solve_parts_timed(
+        &self,
+        input: &str,
+        parts: &[u8],
+    ) -> Result<Vec<Result<Timing, SolverError>>, ParseError>;
+
+    fn solve_timed(&self, input: &str, part: u8) -> Result<Timing, SolverError> {
+        self.solve_parts_timed(input, &[part])?
+            .into_iter()
+            .next()
+            .unwrap_or(Err(SolverError::UnknownPart(part)))
+    }
+
+    fn solve(&self, input: &str, part: u8) -> Result<Answer, SolverError> {
+        self.solve_timed(input, part).map(|timing| timing.answer)
+    }
+}
+
+impl<S: Solution> Solver for S {
+    fn solve_parts_timed(
+        &self,
+        input: &str,
+        parts: &[u8],
+    ) -> Result<Vec<Result<Timing, SolverError>>, ParseError> {
+        let time = Instant::now();
+        let parsed = self.parse(input)?;
+        let parse = time.elapsed();
+
+        Ok(parts
+            .iter()
+            .map(|part| {
+                let time = Instant::now();
+                let answer = match part {
+                    1 => self.part_1(&parsed)?,
+                    2 => self.part_2(&parsed)?,
+                    _ => return Err(SolverError::UnknownPart(*part)),
+                };
+
+                Ok(Timing {
+                    answer,
+                    parse,
+                    solve: time.elapsed(),
+                })
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Lines;
+
+    impl Solution for Lines {
+        type Input<'a> = Vec<&'a str>;
+
+        fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+            if input.is_empty() {
+                return Err(ParseError::new("expected lines", ""));
+            }
+
+            Ok(input.lines().collect())
+        }
+
+        fn part_1(&self, input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+            Ok(input.len().into())
+        }
+
+        fn part_2(&self, _input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+            Err(SolveError::new("no answer"))
+        }
+    }
+
+    #[test]
+    fn solves_parts() {
+        assert_eq!(Lines.solve("a\nb", 1), Ok(Answer::from(2)));
+        assert_eq!(
+            Lines.solve("a\nb", 2),
+            Err(SolverError::Solve(SolveError::new("no answer")))
+        );
+        assert_eq!(Lines.solve("a\nb", 3), Err(SolverError::UnknownPart(3)));
+        assert!(matches!(Lines.solve("", 1), Err(SolverError::Parse(_))));
+    }
+
+    #[test]
+    fn parses_once_for_every_part() {
+        let timings = Lines.solve_parts_timed("a\nb", &[1, 2]).unwrap();
+
+        assert_eq!(timings.len(), 2);
+        assert_eq!(timings[0].as_ref().unwrap().answer, Answer::from(2));
+        assert!(timings[1].is_err());
+        assert!(Lines.solve_parts_timed("", &[1, 2]).is_err());
+    }
+}
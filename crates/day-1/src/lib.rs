@@ -0,0 +1,62 @@
+use aoc_common::{parse, Answer, ParseError, Solution, SolveError};
+
+fn count_elf_calories(data: &str) -> Result<Vec<i32>, ParseError> {
+    parse::blocks(data)
+        .map(|elf| {
+            parse::lines(elf)
+                .map(|l| {
+                    l.trim()
+                        .parse::<i32>()
+                        .map_err(|_| ParseError::at(data, l, "expected a calorie count"))
+                })
+                .sum()
+        })
+        .collect()
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input<'a> = Vec<i32>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        count_elf_calories(input)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        let mut highest = 0;
+
+        for elf_calories in input {
+            if *elf_calories > highest {
+                highest = *elf_calories;
+            }
+        }
+
+        Ok(highest.into())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        let mut calories = input.clone();
+
+        if calories.len() < 3 {
+            return Err(SolveError::new("expected at least 3 elves"));
+        }
+
+        calories.sort();
+
+        let top3 = &calories[(calories.len() - 3)..];
+        let top_calories: i32 = top3.iter().sum();
+
+        Ok(top_calories.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::examples!(Day1, "day-1", {
+        example: { part_1: 24000, part_2: 45000 },
+        ties: { part_1: 300, part_2: 900 },
+    });
+}
@@ -0,0 +1,63 @@
+use aoc_10::{debugger::Debugger, Crt, Day10};
+use aoc_common::{
+    cli::{self, Args},
+    Solution,
+};
+use std::io;
+
+const USAGE: &str = "Usage:
+  cpu-debug [--width <N>] [--height <N>] <FILE>
+
+Commands are read from stdin, so the program must come from a file.";
+
+struct Options {
+    width: usize,
+    height: usize,
+    input: String,
+}
+
+fn parse_args<I: Iterator<Item = String>>(args: I) -> Result<Options, String> {
+    let mut args = Args::new(args);
+    let mut width = 40;
+    let mut height = 6;
+
+    while let Some(flag) = args.next_flag()? {
+        match flag.as_str() {
+            "--width" | "-w" => {
+                width = args.parse_where(&flag, |size| *size > 0)?;
+            }
+            "--height" | "-h" => {
+                height = args.parse_where(&flag, |size| *size > 0)?;
+            }
+            unknown => {
+                return Err(cli::unknown_argument(unknown));
+            }
+        }
+    }
+
+    let input = args.input()?;
+
+    if input == "-" {
+        return Err(String::from(
+            "The program can't be read from stdin, which is used for commands",
+        ));
+    }
+
+    Ok(Options {
+        width,
+        height,
+        input,
+    })
+}
+
+fn main() {
+    let options = parse_args(std::env::args().skip(1))
+        .unwrap_or_else(|error| cli::fail_with_usage(error, USAGE));
+    let input = cli::read_input(&options.input).unwrap_or_else(|error| cli::fail(error));
+    let program = Day10.parse(&input).unwrap_or_else(|error| cli::fail(error));
+    let crt = Crt::new(options.width, options.height);
+
+    if let Err(error) = Debugger::new(&program, crt).run(io::stdin().lock(), io::stdout().lock()) {
+        cli::fail(error);
+    }
+}
@@ -0,0 +1,100 @@
+use aoc_10::{Cpu, Crt, Day10};
+use aoc_common::{
+    cli::{self, Args},
+    Solution,
+};
+use std::{fs, path::PathBuf};
+
+const USAGE: &str = "Usage:
+  crt-render [--width <N>] [--height <N>] [--format <text|unicode|pbm|ppm>]
+             [--scale <N>] [--output <FILE>] <FILE|->";
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Format {
+    Text,
+    Unicode,
+    Pbm,
+    Ppm,
+}
+
+struct Options {
+    width: usize,
+    height: usize,
+    format: Format,
+    /// Size of each pixel within PBM and PPM images.
+    scale: usize,
+    output: Option<PathBuf>,
+    input: String,
+}
+
+fn parse_args<I: Iterator<Item = String>>(args: I) -> Result<Options, String> {
+    let mut args = Args::new(args);
+    let mut options = Options {
+        width: 40,
+        height: 6,
+        format: Format::Text,
+        scale: 1,
+        output: None,
+        input: String::new(),
+    };
+
+    while let Some(flag) = args.next_flag()? {
+        match flag.as_str() {
+            "--width" | "-w" => {
+                options.width = args.parse_where(&flag, |size| *size > 0)?;
+            }
+            "--height" | "-h" => {
+                options.height = args.parse_where(&flag, |size| *size > 0)?;
+            }
+            "--scale" => {
+                options.scale = args.parse_where(&flag, |size| *size > 0)?;
+            }
+            "--format" | "-f" => {
+                options.format = match args.value(&flag)?.as_str() {
+                    "text" => Format::Text,
+                    "unicode" => Format::Unicode,
+                    "pbm" => Format::Pbm,
+                    "ppm" => Format::Ppm,
+                    value => return Err(format!("Invalid value for {}: {}", flag, value)),
+                };
+            }
+            "--output" | "-o" => {
+                options.output = Some(PathBuf::from(args.value(&flag)?));
+            }
+            unknown => {
+                return Err(cli::unknown_argument(unknown));
+            }
+        }
+    }
+
+    options.input = args.input()?;
+
+    Ok(options)
+}
+
+fn main() {
+    let options = parse_args(std::env::args().skip(1))
+        .unwrap_or_else(|error| cli::fail_with_usage(error, USAGE));
+    let input = cli::read_input(&options.input).unwrap_or_else(|error| cli::fail(error));
+    let program = Day10.parse(&input).unwrap_or_else(|error| cli::fail(error));
+
+    let mut crt = Crt::new(options.width, options.height);
+
+    Cpu::new(&program).run_for(options.width * options.height, &mut [&mut crt]);
+
+    let output = match options.format {
+        Format::Text => crt.rows().join("\n") + "\n",
+        Format::Unicode => crt.to_unicode().join("\n") + "\n",
+        Format::Pbm => crt.to_pbm(options.scale),
+        Format::Ppm => crt.to_ppm(options.scale, [255, 204, 0], [15, 15, 35]),
+    };
+
+    match options.output {
+        Some(path) => {
+            if let Err(error) = fs::write(&path, output) {
+                cli::fail(format!("Unable to write {}: {}", path.display(), error));
+            }
+        }
+        None => print!("{}", output),
+    }
+}
@@ -0,0 +1,197 @@
+use aoc_common::ParseError;
+use std::{fmt, str::FromStr};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Instruction {
+    Noop,
+    Addx(i32),
+}
+
+impl Instruction {
+    /// Number of cycles the instruction takes to complete.
+    pub fn cycles(&self) -> usize {
+        match self {
+            Instruction::Noop => 1,
+            Instruction::Addx(_) => 2,
+        }
+    }
+
+    /// Applies the instruction to the register, once all its cycles have passed.
+    fn execute(&self, x: &mut i32) {
+        match self {
+            Instruction::Noop => {}
+            Instruction::Addx(value) => *x += value,
+        }
+    }
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let mut parts = s.split_whitespace();
+
+        match (parts.next(), parts.next(), parts.next()) {
+            (Some("noop"), None, None) => Ok(Instruction::Noop),
+            (Some("addx"), Some(value), None) => value
+                .parse()
+                .map(Instruction::Addx)
+                .map_err(|_| ParseError::new("expected an integer to add", value)),
+            _ => Err(ParseError::new("expected \"noop\" or \"addx N\"", s)),
+        }
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Instruction::Noop => write!(f, "noop"),
+            Instruction::Addx(value) => write!(f, "addx {}", value),
+        }
+    }
+}
+
+/// Receives the state of the CPU during each cycle.
+pub trait Observer {
+    fn observe(&mut self, cycle: usize, x: i32);
+}
+
+/// Executes a program, yielding the cycle number (starting at 1) and the
+/// value of the X register *during* that cycle, until the program ends.
+#[derive(Clone, Debug)]
+pub struct Cpu<'p> {
+    program: &'p [Instruction],
+    /// Index of the instruction being executed.
+    pointer: usize,
+    /// Cycles left until the current instruction completes.
+    remaining: usize,
+    cycle: usize,
+    x: i32,
+}
+
+impl<'p> Cpu<'p> {
+    pub fn new(program: &'p [Instruction]) -> Self {
+        Cpu {
+            program,
+            pointer: 0,
+            remaining: 0,
+            cycle: 0,
+            x: 1,
+        }
+    }
+
+    /// Number of cycles completed so far.
+    pub fn cycles(&self) -> usize {
+        self.cycle
+    }
+
+    pub fn x(&self) -> i32 {
+        self.x
+    }
+
+    /// Index of the instruction that executes during the next cycle.
+    pub fn pointer(&self) -> usize {
+        self.pointer
+    }
+
+    pub fn current(&self) -> Option<Instruction> {
+        self.program.get(self.pointer).copied()
+    }
+
+    /// Runs until `cycles` cycles have passed, notifying every observer of
+    /// each. Once the program ends X holds its last value, and a longer
+    /// program is stopped early.
+    pub fn run_for(&mut self, cycles: usize, observers: &mut [&mut dyn Observer]) {
+        while self.cycle < cycles {
+            let (cycle, x) = self.next().unwrap_or_else(|| {
+                self.cycle += 1;
+                (self.cycle, self.x)
+            });
+
+            for observer in observers.iter_mut() {
+                observer.observe(cycle, x);
+            }
+        }
+    }
+}
+
+impl Iterator for Cpu<'_> {
+    type Item = (usize, i32);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let instruction = self.program.get(self.pointer)?;
+
+        if self.remaining == 0 {
+            self.remaining = instruction.cycles();
+        }
+
+        self.cycle += 1;
+        self.remaining -= 1;
+
+        let during = (self.cycle, self.x);
+
+        if self.remaining == 0 {
+            instruction.execute(&mut self.x);
+            self.pointer += 1;
+        }
+
+        Some(during)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn small_program() {
+        let program = [
+            Instruction::Noop,
+            Instruction::Addx(3),
+            Instruction::Addx(-5),
+        ];
+        let mut cpu = Cpu::new(&program);
+
+        assert_eq!(
+            cpu.by_ref().collect::<Vec<_>>(),
+            vec![(1, 1), (2, 1), (3, 1), (4, 4), (5, 4)]
+        );
+        assert_eq!(cpu.cycles(), 5);
+        assert_eq!(cpu.pointer(), 3);
+        assert_eq!(cpu.current(), None);
+        assert_eq!(cpu.x(), -1);
+    }
+
+    #[test]
+    fn runs_for_cycles() {
+        struct Record(Vec<(usize, i32)>);
+
+        impl Observer for Record {
+            fn observe(&mut self, cycle: usize, x: i32) {
+                self.0.push((cycle, x));
+            }
+        }
+
+        let program = [Instruction::Addx(3), Instruction::Noop, Instruction::Noop];
+        let mut record = Record(vec![]);
+
+        Cpu::new(&program).run_for(5, &mut [&mut record]);
+
+        assert_eq!(record.0, vec![(1, 1), (2, 1), (3, 4), (4, 4), (5, 4)]);
+
+        let mut record = Record(vec![]);
+
+        Cpu::new(&program).run_for(2, &mut [&mut record]);
+
+        assert_eq!(record.0, vec![(1, 1), (2, 1)]);
+    }
+
+    #[test]
+    fn parses() {
+        assert_eq!("noop".parse(), Ok(Instruction::Noop));
+        assert_eq!(Instruction::Addx(-11).to_string(), "addx -11");
+        assert_eq!("addx -11".parse(), Ok(Instruction::Addx(-11)));
+        assert!("addx".parse::<Instruction>().is_err());
+        assert!("noop 1".parse::<Instruction>().is_err());
+    }
+}
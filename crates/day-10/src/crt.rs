@@ -0,0 +1,175 @@
+use crate::cpu::Observer;
+
+pub const CRT_WIDTH: usize = 40;
+pub const CRT_HEIGHT: usize = 6;
+
+/// Draws a pixel per cycle, left to right and top to bottom, lighting it
+/// when the 3 pixel wide sprite centered on X overlaps it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Crt {
+    width: usize,
+    height: usize,
+    pixels: Vec<bool>,
+}
+
+impl Default for Crt {
+    fn default() -> Self {
+        Crt::new(CRT_WIDTH, CRT_HEIGHT)
+    }
+}
+
+impl Crt {
+    pub fn new(width: usize, height: usize) -> Self {
+        assert!(width > 0 && height > 0, "A CRT requires at least 1 pixel.");
+
+        Crt {
+            width,
+            height,
+            pixels: vec![false; width * height],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn is_lit(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.height && self.pixels[y * self.width + x]
+    }
+
+    pub fn rows(&self) -> Vec<String> {
+        self.pixels
+            .chunks(self.width)
+            .map(|row| row.iter().map(|lit| if *lit { '#' } else { '.' }).collect())
+            .collect()
+    }
+
+    /// Two rows per line using half block characters.
+    pub fn to_unicode(&self) -> Vec<String> {
+        (0..self.height)
+            .step_by(2)
+            .map(|y| {
+                (0..self.width)
+                    .map(|x| match (self.is_lit(x, y), self.is_lit(x, y + 1)) {
+                        (true, true) => '█',
+                        (true, false) => '▀',
+                        (false, true) => '▄',
+                        (false, false) => ' ',
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Rows of `scale`d pixels, as an image's pixels from top to bottom.
+    fn scaled(&self, scale: usize) -> impl Iterator<Item = Vec<bool>> + '_ {
+        let scale = scale.max(1);
+
+        (0..self.height * scale).map(move |y| {
+            (0..self.width * scale)
+                .map(|x| self.is_lit(x / scale, y / scale))
+                .collect()
+        })
+    }
+
+    /// A plain (ASCII) PBM image, where lit pixels are black.
+    pub fn to_pbm(&self, scale: usize) -> String {
+        let scale = scale.max(1);
+        let mut image = format!("P1\n{} {}\n", self.width * scale, self.height * scale);
+
+        for row in self.scaled(scale) {
+            let pixels = row
+                .iter()
+                .map(|lit| if *lit { "1" } else { "0" })
+                .collect::<Vec<_>>();
+
+            image.push_str(&pixels.join(" "));
+            image.push('\n');
+        }
+
+        image
+    }
+
+    /// A plain (ASCII) PPM image with the given RGB colors.
+    pub fn to_ppm(&self, scale: usize, lit: [u8; 3], unlit: [u8; 3]) -> String {
+        let scale = scale.max(1);
+        let mut image = format!("P3\n{} {}\n255\n", self.width * scale, self.height * scale);
+
+        for row in self.scaled(scale) {
+            let pixels = row
+                .iter()
+                .map(|on| {
+                    let [r, g, b] = if *on { lit } else { unlit };
+
+                    format!("{} {} {}", r, g, b)
+                })
+                .collect::<Vec<_>>();
+
+            image.push_str(&pixels.join("  "));
+            image.push('\n');
+        }
+
+        image
+    }
+}
+
+impl Observer for Crt {
+    fn observe(&mut self, cycle: usize, x: i32) {
+        let index = cycle - 1;
+
+        if index >= self.width * self.height {
+            return;
+        }
+
+        let column = (index % self.width) as i32;
+
+        self.pixels[index] = (column - x).abs() <= 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_crt() -> Crt {
+        let mut crt = Crt::new(3, 3);
+
+        // Draws #.. .## ..#
+        for (cycle, x) in [(1, 0), (2, 3), (3, 4), (4, 5), (5, 0), (6, 1)] {
+            crt.observe(cycle, x);
+        }
+
+        crt.observe(9, 2);
+        crt
+    }
+
+    #[test]
+    fn draws_custom_size() {
+        let mut crt = create_crt();
+
+        assert_eq!(crt.rows(), vec!["#..", ".##", "..#"]);
+
+        // Cycles beyond the screen are ignored
+        crt.observe(10, 0);
+        assert_eq!(crt.rows().len(), 3);
+    }
+
+    #[test]
+    fn exports() {
+        let crt = create_crt();
+
+        assert_eq!(crt.to_unicode(), vec!["▀▄▄", "  ▀"]);
+        assert_eq!(crt.to_pbm(1), "P1\n3 3\n1 0 0\n0 1 1\n0 0 1\n");
+        assert!(crt
+            .to_pbm(2)
+            .starts_with("P1\n6 6\n1 1 0 0 0 0\n1 1 0 0 0 0\n0 0 1 1 1 1\n"));
+        assert_eq!(
+            crt.to_ppm(1, [255, 255, 255], [0, 0, 0]).lines().nth(3),
+            Some("255 255 255  0 0 0  0 0 0")
+        );
+    }
+}
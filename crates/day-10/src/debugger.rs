@@ -0,0 +1,383 @@
+use crate::{
+    cpu::{Cpu, Instruction, Observer},
+    crt::Crt,
+};
+use std::{
+    fmt,
+    io::{self, BufRead, Write},
+};
+
+pub const HELP: &str = "Commands:
+  s, step [N]                 Run N cycles (default 1)
+  n, next [N]                 Run until N instructions complete (default 1)
+  c, continue                 Run until a breakpoint or the end of the program
+  b, break cycle <N>          Break during cycle N
+  b, break instruction <N>    Break when instruction N (from 0) starts
+  b, break x <N>              Break when the X register becomes N
+  d, delete                   Remove all breakpoints
+  p, print                    Show the current state
+  h, help                     Show this help
+  q, quit                     Exit the debugger";
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Breakpoint {
+    Cycle(usize),
+    Instruction(usize),
+    Register(i32),
+}
+
+impl fmt::Display for Breakpoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Breakpoint::Cycle(cycle) => write!(f, "cycle {}", cycle),
+            Breakpoint::Instruction(index) => write!(f, "instruction {}", index),
+            Breakpoint::Register(value) => write!(f, "x = {}", value),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Command {
+    Step(usize),
+    Next(usize),
+    Continue,
+    Break(Breakpoint),
+    Delete,
+    Print,
+    Help,
+    Quit,
+}
+
+fn parse_count(value: Option<&str>) -> Result<usize, String> {
+    match value {
+        None => Ok(1),
+        Some(value) => value
+            .parse()
+            .map_err(|_| format!("Invalid count {}", value)),
+    }
+}
+
+pub fn parse_command(line: &str) -> Result<Command, String> {
+    let mut parts = line.split_whitespace();
+    let command = parts.next().unwrap_or("step");
+    let argument = parts.next();
+
+    let parsed = match command {
+        "s" | "step" => Command::Step(parse_count(argument)?),
+        "n" | "next" => Command::Next(parse_count(argument)?),
+        "c" | "continue" => Command::Continue,
+        "b" | "break" => {
+            let value = parts
+                .next()
+                .ok_or_else(|| String::from("Missing breakpoint value"))?;
+            let invalid = |_| format!("Invalid breakpoint value {}", value);
+
+            Command::Break(match argument {
+                Some("cycle") => Breakpoint::Cycle(value.parse().map_err(invalid)?),
+                Some("instruction") => Breakpoint::Instruction(value.parse().map_err(invalid)?),
+                Some("x") => Breakpoint::Register(value.parse().map_err(invalid)?),
+                _ => {
+                    return Err(String::from(
+                        "Breakpoints must be on a cycle, instruction or x",
+                    ));
+                }
+            })
+        }
+        "d" | "delete" => Command::Delete,
+        "p" | "print" => Command::Print,
+        "h" | "help" => Command::Help,
+        "q" | "quit" => Command::Quit,
+        unknown => return Err(format!("Unknown command {}", unknown)),
+    };
+
+    if parts.next().is_some() {
+        return Err(format!("Too many arguments for {}", command));
+    }
+
+    Ok(parsed)
+}
+
+/// The state during the most recently executed cycle.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Snapshot {
+    cycle: usize,
+    x: i32,
+    pointer: usize,
+    instruction: Instruction,
+    /// Whether the instruction started during this cycle.
+    started: bool,
+}
+
+/// Steps through a program while drawing to a CRT, so the state can be
+/// inspected mid-run.
+pub struct Debugger<'p> {
+    cpu: Cpu<'p>,
+    crt: Crt,
+    breakpoints: Vec<Breakpoint>,
+    last: Option<Snapshot>,
+}
+
+impl<'p> Debugger<'p> {
+    /// Runs the program while drawing to the CRT, which sets the width of
+    /// the sprite and row shown in the status.
+    pub fn new(program: &'p [Instruction], crt: Crt) -> Self {
+        Debugger {
+            cpu: Cpu::new(program),
+            crt,
+            breakpoints: vec![],
+            last: None,
+        }
+    }
+
+    pub fn add_breakpoint(&mut self, breakpoint: Breakpoint) {
+        self.breakpoints.push(breakpoint);
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.cpu.current().is_none()
+    }
+
+    /// Runs a single cycle, returning false if the program has ended.
+    pub fn step(&mut self) -> bool {
+        let pointer = self.cpu.pointer();
+        let Some(instruction) = self.cpu.current() else {
+            return false;
+        };
+        let Some((cycle, x)) = self.cpu.next() else {
+            return false;
+        };
+
+        self.crt.observe(cycle, x);
+        self.last = Some(Snapshot {
+            cycle,
+            x,
+            pointer,
+            instruction,
+            started: self.last.is_none_or(|last| last.pointer != pointer),
+        });
+
+        true
+    }
+
+    /// Runs until the current instruction completes.
+    pub fn next_instruction(&mut self) -> bool {
+        let pointer = self.cpu.pointer();
+
+        while self.step() {
+            if self.cpu.pointer() != pointer {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    fn hit(&self, previous: Option<Snapshot>) -> Option<Breakpoint> {
+        let last = self.last?;
+
+        self.breakpoints
+            .iter()
+            .find(|breakpoint| match breakpoint {
+                Breakpoint::Cycle(cycle) => last.cycle == *cycle,
+                Breakpoint::Instruction(index) => last.started && last.pointer == *index,
+                Breakpoint::Register(value) => {
+                    last.x == *value && previous.is_none_or(|previous| previous.x != *value)
+                }
+            })
+            .copied()
+    }
+
+    /// Runs at least one cycle, then until a breakpoint is hit or the program ends.
+    pub fn resume(&mut self) -> Option<Breakpoint> {
+        loop {
+            let previous = self.last;
+
+            if !self.step() {
+                return None;
+            }
+
+            if let Some(breakpoint) = self.hit(previous) {
+                return Some(breakpoint);
+            }
+        }
+    }
+
+    /// The current instruction, X register, sprite position and CRT row.
+    pub fn status(&self) -> String {
+        let Some(last) = self.last else {
+            return String::from("Not started");
+        };
+        let width = self.crt.width();
+        let index = last.cycle - 1;
+        let row = self
+            .crt
+            .rows()
+            .get(index / width)
+            .map(|row| row[..=index % width].to_owned())
+            .unwrap_or_default();
+        let sprite = (0..width as i32)
+            .map(|column| {
+                if (column - last.x).abs() <= 1 {
+                    '#'
+                } else {
+                    '.'
+                }
+            })
+            .collect::<String>();
+
+        format!(
+            "Cycle {}: instruction {} ({}), X = {}\nSprite position: {}\nCurrent CRT row: {}",
+            last.cycle, last.pointer, last.instruction, last.x, sprite, row
+        )
+    }
+
+    /// Reads commands line by line until the input ends or `quit`.
+    pub fn run<R: BufRead, W: Write>(&mut self, input: R, mut output: W) -> io::Result<()> {
+        writeln!(output, "{}", HELP)?;
+
+        for line in input.lines() {
+            let command = match parse_command(&line?) {
+                Ok(command) => command,
+                Err(error) => {
+                    writeln!(output, "{}", error)?;
+                    continue;
+                }
+            };
+
+            match command {
+                Command::Step(count) => {
+                    for _ in 0..count {
+                        self.step();
+                    }
+                }
+                Command::Next(count) => {
+                    for _ in 0..count {
+                        self.next_instruction();
+                    }
+                }
+                Command::Continue => {
+                    if let Some(breakpoint) = self.resume() {
+                        writeln!(output, "Hit breakpoint on {}", breakpoint)?;
+                    }
+                }
+                Command::Break(breakpoint) => {
+                    self.add_breakpoint(breakpoint);
+                    writeln!(output, "Added breakpoint on {}", breakpoint)?;
+                    continue;
+                }
+                Command::Delete => {
+                    self.breakpoints.clear();
+                    writeln!(output, "Removed all breakpoints")?;
+                    continue;
+                }
+                Command::Print => {}
+                Command::Help => {
+                    writeln!(output, "{}", HELP)?;
+                    continue;
+                }
+                Command::Quit => break,
+            }
+
+            writeln!(output, "{}", self.status())?;
+
+            if self.is_done() {
+                writeln!(output, "Program ended")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PROGRAM: [Instruction; 4] = [
+        Instruction::Addx(15),
+        Instruction::Addx(-11),
+        Instruction::Noop,
+        Instruction::Addx(6),
+    ];
+
+    #[test]
+    fn parses_commands() {
+        assert_eq!(parse_command(""), Ok(Command::Step(1)));
+        assert_eq!(parse_command("n 3"), Ok(Command::Next(3)));
+        assert_eq!(
+            parse_command("break x 5"),
+            Ok(Command::Break(Breakpoint::Register(5)))
+        );
+        assert!(parse_command("break pc 5").is_err());
+        assert!(parse_command("step x").is_err());
+        assert!(parse_command("jump").is_err());
+    }
+
+    #[test]
+    fn steps() {
+        let mut debugger = Debugger::new(&PROGRAM, Crt::default());
+
+        assert!(debugger.step());
+        assert_eq!(
+            debugger.status(),
+            "Cycle 1: instruction 0 (addx 15), X = 1\n\
+             Sprite position: ###.....................................\n\
+             Current CRT row: #"
+        );
+
+        assert!(debugger.next_instruction());
+        assert!(debugger.next_instruction());
+        assert_eq!(
+            debugger.status(),
+            "Cycle 4: instruction 1 (addx -11), X = 16\n\
+             Sprite position: ...............###......................\n\
+             Current CRT row: ##.."
+        );
+    }
+
+    #[test]
+    fn uses_crt_size() {
+        let mut debugger = Debugger::new(&PROGRAM, Crt::new(4, 2));
+
+        for _ in 0..6 {
+            assert!(debugger.step());
+        }
+
+        assert_eq!(
+            debugger.status(),
+            "Cycle 6: instruction 3 (addx 6), X = 5\n\
+             Sprite position: ....\n\
+             Current CRT row: .."
+        );
+    }
+
+    #[test]
+    fn breakpoints() {
+        let mut debugger = Debugger::new(&PROGRAM, Crt::default());
+
+        debugger.add_breakpoint(Breakpoint::Register(5));
+        debugger.add_breakpoint(Breakpoint::Instruction(3));
+
+        assert_eq!(debugger.resume(), Some(Breakpoint::Register(5)));
+        assert_eq!(debugger.last.map(|last| last.cycle), Some(5));
+        assert_eq!(debugger.resume(), Some(Breakpoint::Instruction(3)));
+        assert_eq!(debugger.last.map(|last| last.cycle), Some(6));
+        assert_eq!(debugger.resume(), None);
+        assert!(debugger.is_done());
+    }
+
+    #[test]
+    fn runs_commands() {
+        let mut debugger = Debugger::new(&PROGRAM, Crt::default());
+        let mut output = vec![];
+
+        debugger
+            .run("b cycle 3\nc\nfoo\nq\nc\n".as_bytes(), &mut output)
+            .unwrap();
+
+        let output = String::from_utf8(output).unwrap();
+
+        assert!(output.contains("Added breakpoint on cycle 3\nHit breakpoint on cycle 3\nCycle 3:"));
+        assert!(output.ends_with("Unknown command foo\n"));
+    }
+}
@@ -0,0 +1,142 @@
+use aoc_common::{parse, Answer, ParseError, Solution, SolveError};
+use cpu::Observer;
+use ocr::OcrError;
+
+mod cpu;
+mod crt;
+pub mod debugger;
+pub mod ocr;
+
+pub use cpu::{Cpu, Instruction};
+pub use crt::{Crt, CRT_HEIGHT, CRT_WIDTH};
+
+fn parse_instructions(data: &str) -> Result<Vec<Instruction>, ParseError> {
+    parse::lines(data)
+        .map(|line| {
+            line.trim()
+                .parse::<Instruction>()
+                .map_err(|error| error.locate(data, line))
+        })
+        .collect()
+}
+
+/// Sums the cycle number multiplied by the X register during the 20th
+/// cycle, and every 40 cycles after that up to the 220th.
+#[derive(Debug, Default)]
+pub struct SignalStrength {
+    pub total: i64,
+}
+
+impl Observer for SignalStrength {
+    fn observe(&mut self, cycle: usize, x: i32) {
+        if cycle <= 220 && cycle % 40 == 20 {
+            self.total += cycle as i64 * x as i64;
+        }
+    }
+}
+
+/// Cycles the program runs for, drawing every pixel of the default CRT.
+const CYCLES: usize = CRT_WIDTH * CRT_HEIGHT;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input<'a> = Vec<Instruction>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        parse_instructions(input)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        let mut signal = SignalStrength::default();
+
+        Cpu::new(input).run_for(CYCLES, &mut [&mut signal]);
+
+        Ok(signal.total.into())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        let mut crt = Crt::default();
+
+        Cpu::new(input).run_for(CYCLES, &mut [&mut crt]);
+
+        let rows = crt.rows();
+
+        // Screens that aren't made of letters, like the example, are drawn instead
+        match ocr::decode(&rows) {
+            Ok(letters) => Ok(Answer::Text(letters)),
+            Err(OcrError::NotLetters) => Ok(Answer::Art(rows)),
+            Err(error) => Err(SolveError::new(error.to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::examples!(Day10, "day-10", {
+        example: {
+            part_1: 13140,
+            part_2: [
+                "##..##..##..##..##..##..##..##..##..##..",
+                "###...###...###...###...###...###...###.",
+                "####....####....####....####....####....",
+                "#####.....#####.....#####.....#####.....",
+                "######......######......######......####",
+                "#######.......#######.......#######.....",
+            ],
+        },
+    });
+
+    #[test]
+    fn works() {
+        let data = include_str!("../../../examples/day-10/example.txt");
+        let program = parse_instructions(data).unwrap();
+        let mut signal = SignalStrength::default();
+        let mut crt = Crt::default();
+
+        Cpu::new(&program).run_for(CYCLES, &mut [&mut signal, &mut crt]);
+
+        assert_eq!(signal.total, 13140);
+        assert_eq!(crt.rows()[0], "##..##..##..##..##..##..##..##..##..##..");
+    }
+
+    #[test]
+    fn short_program() {
+        let program = vec![Instruction::Noop; 100];
+
+        assert_eq!(Day10.part_1(&program), Ok(Answer::Integer(720)));
+
+        let mut crt = Crt::default();
+
+        Cpu::new(&program).run_for(CYCLES, &mut [&mut crt]);
+
+        // X stays at 1 after the program ends, so every row is lit the same
+        assert!(crt
+            .rows()
+            .iter()
+            .all(|row| row == &format!("###{}", ".".repeat(37))));
+    }
+
+    #[test]
+    fn long_program() {
+        let program = vec![Instruction::Noop; 300];
+
+        assert_eq!(Day10.part_1(&program), Ok(Answer::Integer(720)));
+    }
+
+    #[test]
+    fn invalid_instruction() {
+        assert_eq!(
+            parse_instructions("noop\naddx ten\n")
+                .unwrap_err()
+                .to_string(),
+            "line 2, column 6: expected an integer to add, found \"ten\""
+        );
+        assert_eq!(
+            parse_instructions("noop\njmp 4\n").unwrap_err().to_string(),
+            "line 2: expected \"noop\" or \"addx N\", found \"jmp 4\""
+        );
+    }
+}
@@ -0,0 +1,186 @@
+use std::fmt;
+
+pub const GLYPH_WIDTH: usize = 4;
+pub const GLYPH_HEIGHT: usize = 6;
+
+/// Blank columns between each glyph.
+const GLYPH_SPACING: usize = 1;
+
+/// Capital letters drawn by the puzzle's CRT, row by row.
+const GLYPHS: &[(char, [&str; GLYPH_HEIGHT])] = &[
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+#[derive(Debug, PartialEq)]
+pub enum OcrError {
+    /// The screen isn't a single row of glyphs.
+    Height(usize),
+    /// A pixel that is neither lit (`#`) nor dark (`.`).
+    Pixel {
+        row: usize,
+        column: usize,
+        found: char,
+    },
+    /// The columns between glyphs aren't dark, so the screen is a picture
+    /// rather than letters.
+    NotLetters,
+    /// The glyph at the index (counting from the left) isn't a known letter.
+    Unknown { index: usize, glyph: Vec<String> },
+}
+
+impl fmt::Display for OcrError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OcrError::Height(height) => {
+                write!(
+                    f,
+                    "expected a screen {} pixels tall, found {}",
+                    GLYPH_HEIGHT, height
+                )
+            }
+            OcrError::Pixel { row, column, found } => {
+                write!(
+                    f,
+                    "expected a pixel of # or . at row {}, column {}, found {:?}",
+                    row + 1,
+                    column + 1,
+                    found
+                )
+            }
+            OcrError::NotLetters => write!(f, "the screen isn't spaced as letters"),
+            OcrError::Unknown { index, glyph } => {
+                write!(f, "unknown glyph {}:\n{}", index + 1, glyph.join("\n"))
+            }
+        }
+    }
+}
+
+impl std::error::Error for OcrError {}
+
+/// Reads the letters drawn on a screen of `#` and `.` pixels.
+pub fn decode<R: AsRef<str>>(rows: &[R]) -> Result<String, OcrError> {
+    if rows.len() != GLYPH_HEIGHT {
+        return Err(OcrError::Height(rows.len()));
+    }
+
+    for (y, row) in rows.iter().enumerate() {
+        if let Some((x, found)) = row
+            .as_ref()
+            .chars()
+            .enumerate()
+            .find(|(_, c)| *c != '#' && *c != '.')
+        {
+            return Err(OcrError::Pixel {
+                row: y,
+                column: x,
+                found,
+            });
+        }
+    }
+
+    // Every pixel is ASCII, so rows can be sliced by byte
+    let width = rows.iter().map(|row| row.as_ref().len()).min().unwrap_or(0);
+    let count = (width + GLYPH_SPACING) / (GLYPH_WIDTH + GLYPH_SPACING);
+    let spaced = (0..count)
+        .map(|index| index * (GLYPH_WIDTH + GLYPH_SPACING) + GLYPH_WIDTH)
+        .filter(|x| *x < width)
+        .all(|x| rows.iter().all(|row| row.as_ref().as_bytes()[x] == b'.'));
+
+    if !spaced {
+        return Err(OcrError::NotLetters);
+    }
+
+    (0..count)
+        .map(|index| {
+            let start = index * (GLYPH_WIDTH + GLYPH_SPACING);
+            let glyph = rows
+                .iter()
+                .map(|row| row.as_ref()[start..start + GLYPH_WIDTH].to_owned())
+                .collect::<Vec<_>>();
+
+            GLYPHS
+                .iter()
+                .find(|(_, pixels)| pixels.iter().eq(glyph.iter()))
+                .map(|(letter, _)| *letter)
+                .ok_or(OcrError::Unknown { index, glyph })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn screen(letters: &str) -> Vec<String> {
+        (0..GLYPH_HEIGHT)
+            .map(|y| {
+                letters
+                    .chars()
+                    .map(|letter| {
+                        GLYPHS
+                            .iter()
+                            .find(|(other, _)| *other == letter)
+                            .map_or("####", |(_, pixels)| pixels[y])
+                    })
+                    .collect::<Vec<_>>()
+                    .join(".")
+            })
+            .collect()
+    }
+
+    #[test]
+    fn decodes_letters() {
+        let letters = GLYPHS.iter().map(|(letter, _)| *letter).collect::<String>();
+
+        assert_eq!(decode(&screen(&letters)), Ok(letters));
+        assert_eq!(decode(&screen("EHPZPJGL")), Ok(String::from("EHPZPJGL")));
+    }
+
+    #[test]
+    fn unknown_glyphs() {
+        assert_eq!(
+            decode(&screen("AB?")),
+            Err(OcrError::Unknown {
+                index: 2,
+                glyph: vec![String::from("####"); GLYPH_HEIGHT],
+            })
+        );
+        assert_eq!(decode(&["####"]), Err(OcrError::Height(1)));
+    }
+
+    #[test]
+    fn rejects_other_screens() {
+        let mut rows = screen("AB");
+
+        rows[2].replace_range(6..7, "█");
+
+        assert_eq!(
+            decode(&rows),
+            Err(OcrError::Pixel {
+                row: 2,
+                column: 6,
+                found: '█',
+            })
+        );
+
+        rows = vec![String::from("##..##..##"); GLYPH_HEIGHT];
+
+        assert_eq!(decode(&rows), Err(OcrError::NotLetters));
+    }
+}
@@ -0,0 +1,303 @@
+use crate::{
+    expr::{EvalError, Op},
+    worry::Worry,
+};
+use std::{cmp::Ordering, fmt};
+
+/// An arbitrary precision unsigned integer, as little endian 32-bit limbs
+/// without trailing zeros.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct BigUint {
+    limbs: Vec<u32>,
+}
+
+impl BigUint {
+    pub fn new(value: u64) -> Self {
+        let mut number = BigUint {
+            limbs: vec![value as u32, (value >> 32) as u32],
+        };
+
+        number.normalize();
+        number
+    }
+
+    fn normalize(&mut self) {
+        while self.limbs.last() == Some(&0) {
+            self.limbs.pop();
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    fn bits(&self) -> usize {
+        self.limbs.last().map_or(0, |top| {
+            self.limbs.len() * 32 - top.leading_zeros() as usize
+        })
+    }
+
+    fn bit(&self, index: usize) -> bool {
+        self.limbs
+            .get(index / 32)
+            .is_some_and(|limb| limb >> (index % 32) & 1 == 1)
+    }
+
+    fn shift_left_one(&mut self) {
+        let mut carry = 0;
+
+        for limb in self.limbs.iter_mut() {
+            let next = *limb >> 31;
+            *limb = *limb << 1 | carry;
+            carry = next;
+        }
+
+        if carry > 0 {
+            self.limbs.push(carry);
+        }
+    }
+
+    pub fn add(&self, other: &BigUint) -> BigUint {
+        let mut limbs = Vec::with_capacity(self.limbs.len().max(other.limbs.len()) + 1);
+        let mut carry = 0u64;
+
+        for i in 0..self.limbs.len().max(other.limbs.len()) {
+            let sum = *self.limbs.get(i).unwrap_or(&0) as u64
+                + *other.limbs.get(i).unwrap_or(&0) as u64
+                + carry;
+
+            limbs.push(sum as u32);
+            carry = sum >> 32;
+        }
+
+        limbs.push(carry as u32);
+
+        let mut number = BigUint { limbs };
+        number.normalize();
+        number
+    }
+
+    /// Returns `None` if `other` is larger.
+    pub fn checked_sub(&self, other: &BigUint) -> Option<BigUint> {
+        if *self < *other {
+            return None;
+        }
+
+        let mut limbs = Vec::with_capacity(self.limbs.len());
+        let mut borrow = 0i64;
+
+        for (i, limb) in self.limbs.iter().enumerate() {
+            let mut difference = *limb as i64 - *other.limbs.get(i).unwrap_or(&0) as i64 - borrow;
+
+            borrow = if difference < 0 {
+                difference += 1 << 32;
+                1
+            } else {
+                0
+            };
+
+            limbs.push(difference as u32);
+        }
+
+        let mut number = BigUint { limbs };
+        number.normalize();
+        Some(number)
+    }
+
+    pub fn mul(&self, other: &BigUint) -> BigUint {
+        if self.is_zero() || other.is_zero() {
+            return BigUint::default();
+        }
+
+        let mut limbs = vec![0u32; self.limbs.len() + other.limbs.len()];
+
+        for (i, a) in self.limbs.iter().enumerate() {
+            let mut carry = 0u64;
+
+            for (j, b) in other.limbs.iter().enumerate() {
+                let product = *a as u64 * *b as u64 + limbs[i + j] as u64 + carry;
+
+                limbs[i + j] = product as u32;
+                carry = product >> 32;
+            }
+
+            limbs[i + other.limbs.len()] = carry as u32;
+        }
+
+        let mut number = BigUint { limbs };
+        number.normalize();
+        number
+    }
+
+    /// The quotient and remainder, or `None` when dividing by zero.
+    pub fn div_rem(&self, divisor: &BigUint) -> Option<(BigUint, BigUint)> {
+        if divisor.is_zero() {
+            return None;
+        }
+
+        if let [small] = divisor.limbs[..] {
+            let (quotient, remainder) = self.div_rem_small(small);
+
+            return Some((quotient, BigUint::new(remainder as u64)));
+        }
+
+        // Binary long division, as divisors this large are rare
+        let mut quotient = BigUint {
+            limbs: vec![0; self.limbs.len()],
+        };
+        let mut remainder = BigUint::default();
+
+        for index in (0..self.bits()).rev() {
+            remainder.shift_left_one();
+
+            if self.bit(index) {
+                match remainder.limbs.first_mut() {
+                    Some(limb) => *limb |= 1,
+                    None => remainder.limbs.push(1),
+                }
+            }
+
+            if remainder >= *divisor {
+                remainder = remainder.checked_sub(divisor)?;
+                quotient.limbs[index / 32] |= 1 << (index % 32);
+            }
+        }
+
+        quotient.normalize();
+        Some((quotient, remainder))
+    }
+
+    fn div_rem_small(&self, divisor: u32) -> (BigUint, u32) {
+        let mut limbs = vec![0; self.limbs.len()];
+        let mut remainder = 0u64;
+
+        for (i, limb) in self.limbs.iter().enumerate().rev() {
+            let current = remainder << 32 | *limb as u64;
+
+            limbs[i] = (current / divisor as u64) as u32;
+            remainder = current % divisor as u64;
+        }
+
+        let mut quotient = BigUint { limbs };
+        quotient.normalize();
+        (quotient, remainder as u32)
+    }
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.limbs
+            .len()
+            .cmp(&other.limbs.len())
+            .then_with(|| self.limbs.iter().rev().cmp(other.limbs.iter().rev()))
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+
+        // Peel off 9 decimal digits at a time
+        let mut chunks = vec![];
+        let mut rest = self.clone();
+
+        while !rest.is_zero() {
+            let (quotient, remainder) = rest.div_rem_small(1_000_000_000);
+
+            chunks.push(remainder);
+            rest = quotient;
+        }
+
+        let mut chunks = chunks.iter().rev();
+
+        if let Some(first) = chunks.next() {
+            write!(f, "{}", first)?;
+        }
+
+        for chunk in chunks {
+            write!(f, "{:09}", chunk)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Worry for BigUint {
+    fn constant(&self, value: u64) -> Self {
+        BigUint::new(value)
+    }
+
+    fn apply(&self, op: Op, rhs: &Self) -> Result<Self, EvalError> {
+        match op {
+            Op::Add => Ok(self.add(rhs)),
+            Op::Subtract => self.checked_sub(rhs).ok_or(EvalError::Underflow),
+            Op::Multiply => Ok(self.mul(rhs)),
+            Op::Divide => self
+                .div_rem(rhs)
+                .map(|(quotient, _)| quotient)
+                .ok_or(EvalError::DivideByZero),
+            Op::Remainder => self
+                .div_rem(rhs)
+                .map(|(_, remainder)| remainder)
+                .ok_or(EvalError::DivideByZero),
+        }
+    }
+
+    fn is_divisible_by(&self, divisor: u64) -> bool {
+        self.div_rem(&BigUint::new(divisor))
+            .is_some_and(|(_, remainder)| remainder.is_zero())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn big(value: u128) -> BigUint {
+        BigUint::new(value as u64).add(
+            &BigUint::new((value >> 64) as u64)
+                .mul(&BigUint::new(1 << 32).mul(&BigUint::new(1 << 32))),
+        )
+    }
+
+    #[test]
+    fn arithmetic() {
+        let a = 0xdead_beef_1234_5678_9abc_u128;
+        let b = 0x1_0000_0001_u128;
+
+        assert_eq!(big(a).add(&big(b)), big(a + b));
+        assert_eq!(big(a).checked_sub(&big(b)), Some(big(a - b)));
+        assert_eq!(big(b).checked_sub(&big(a)), None);
+        assert_eq!(big(a).mul(&big(b)), big(a * b));
+        assert_eq!(big(a).div_rem(&big(b)), Some((big(a / b), big(a % b))));
+        assert_eq!(big(a).div_rem(&big(7)), Some((big(a / 7), big(a % 7))));
+        assert_eq!(big(a).div_rem(&BigUint::default()), None);
+    }
+
+    #[test]
+    fn grows_without_overflow() {
+        let mut value = BigUint::new(u64::MAX);
+
+        for _ in 0..3 {
+            value = value.mul(&value);
+        }
+
+        assert_eq!(value.bits(), 512);
+        assert!(value.is_divisible_by(5));
+        assert_eq!(BigUint::new(79).mul(&BigUint::new(79)).to_string(), "6241");
+        assert_eq!(
+            BigUint::new(u64::MAX)
+                .mul(&BigUint::new(u64::MAX))
+                .to_string(),
+            (u64::MAX as u128 * u64::MAX as u128).to_string()
+        );
+    }
+}
@@ -0,0 +1,139 @@
+use aoc_11::{
+    calculate_monkey_business, count_inspections, report_inspections, BigUint, Day11, EvalError,
+    Monkey, Numeric, Relief, Simulation, Strategy, Worry,
+};
+use aoc_common::{
+    cli::{self, Args},
+    Solution,
+};
+use std::fmt::Display;
+
+const USAGE: &str = "Usage:
+  monkey-business [--rounds <N>] [--relief <N|none>] [--numeric <native|bigint|residues>]
+                  [--strategy <simulate|fast-forward>] [--trace] <FILE|->
+
+Options:
+  --strategy  Play every round, or extrapolate each item's cycle for huge round counts
+  --trace     Narrate every inspection and the items held after each round";
+
+struct Options {
+    rounds: usize,
+    relief: Relief,
+    numeric: Numeric,
+    strategy: Strategy,
+    trace: bool,
+    input: String,
+}
+
+fn parse_args<I: Iterator<Item = String>>(args: I) -> Result<Options, String> {
+    let mut args = Args::new(args);
+    let mut options = Options {
+        rounds: 20,
+        relief: Relief::DivideBy(3),
+        numeric: Numeric::Native,
+        strategy: Strategy::Simulate,
+        trace: false,
+        input: String::new(),
+    };
+
+    while let Some(flag) = args.next_flag()? {
+        match flag.as_str() {
+            "--rounds" | "-r" => {
+                options.rounds = args.parse(&flag)?;
+            }
+            "--relief" => {
+                let value = args.value(&flag)?;
+
+                options.relief = match value.as_str() {
+                    "none" | "1" => Relief::None,
+                    _ => Relief::DivideBy(
+                        value
+                            .parse()
+                            .ok()
+                            .filter(|divisor| *divisor > 0)
+                            .ok_or_else(|| format!("Invalid value for {}: {}", flag, value))?,
+                    ),
+                };
+            }
+            "--numeric" | "-n" => {
+                options.numeric = args.value(&flag)?.parse()?;
+            }
+            "--strategy" | "-s" => {
+                options.strategy = args.value(&flag)?.parse()?;
+            }
+            "--trace" | "-t" => {
+                options.trace = true;
+            }
+            unknown => {
+                return Err(cli::unknown_argument(unknown));
+            }
+        }
+    }
+
+    options.input = args.input()?;
+
+    if options.trace && options.strategy == Strategy::FastForward {
+        return Err(String::from(
+            "Rounds can't be narrated when fast forwarding, use --strategy simulate",
+        ));
+    }
+
+    if options.trace && options.numeric == Numeric::Residues {
+        return Err(String::from(
+            "Worry levels can't be narrated as residues, use --numeric native or bigint",
+        ));
+    }
+
+    Ok(options)
+}
+
+/// Plays the rounds narrating each one, returning the inspection counts.
+fn trace<W: Worry + Display>(
+    monkeys: &[Monkey],
+    options: &Options,
+    worry_level: impl Fn(u64) -> W,
+) -> Result<Vec<usize>, EvalError> {
+    let mut simulation = Simulation::new(monkeys, options.relief, worry_level);
+
+    for _ in 0..options.rounds {
+        let round = simulation.trace_round()?;
+
+        for line in simulation.narrate(&round) {
+            println!("{}", line);
+        }
+
+        println!();
+    }
+
+    Ok(simulation.inspect_counts)
+}
+
+fn main() {
+    let options = parse_args(std::env::args().skip(1))
+        .unwrap_or_else(|error| cli::fail_with_usage(error, USAGE));
+    let input = cli::read_input(&options.input).unwrap_or_else(|error| cli::fail(error));
+    let monkeys = Day11.parse(&input).unwrap_or_else(|error| cli::fail(error));
+
+    let counts = match (options.trace, options.numeric) {
+        (true, Numeric::BigInt) => trace(&monkeys, &options, BigUint::new),
+        (true, _) => trace(&monkeys, &options, u128::from),
+        (false, numeric) => count_inspections(
+            &monkeys,
+            options.rounds,
+            options.relief,
+            numeric,
+            options.strategy,
+        ),
+    };
+
+    match counts {
+        Ok(counts) => {
+            for line in report_inspections(&counts) {
+                println!("{}", line);
+            }
+
+            println!("Monkey business: {}", calculate_monkey_business(&counts));
+        }
+        Err(error) => cli::fail(error),
+    }
+}
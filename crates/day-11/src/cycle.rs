@@ -0,0 +1,212 @@
+use crate::{EvalError, Monkey, Relief, Worry};
+use std::{collections::HashMap, hash::Hash, str::FromStr};
+
+/// How the rounds are played when counting inspections.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Strategy {
+    /// Play every round with every item, as the monkeys do.
+    #[default]
+    Simulate,
+    /// Follow each item until it revisits a state, then extrapolate the
+    /// cycle, so the cost is bounded by the cycle length and not the rounds.
+    FastForward,
+}
+
+impl FromStr for Strategy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, String> {
+        match s {
+            "simulate" | "naive" => Ok(Strategy::Simulate),
+            "fast-forward" | "cycles" => Ok(Strategy::FastForward),
+            _ => Err(format!(
+                "Unknown strategy {}, expected simulate or fast-forward",
+                s
+            )),
+        }
+    }
+}
+
+/// Plays a round for a single item, starting with `holder`, adding the
+/// inspections made to `counts`. An item thrown to a later monkey is
+/// inspected again within the same round, so returns the monkey holding
+/// the item and its worry level at the start of the next round.
+fn play_item_round<W: Worry>(
+    monkeys: &[Monkey],
+    relief: Relief,
+    (mut holder, mut worry_level): (usize, W),
+    counts: &mut [usize],
+) -> Result<(usize, W), EvalError> {
+    loop {
+        let monkey = &monkeys[holder];
+
+        worry_level = relief.apply(monkey.inspect_item(&worry_level)?)?;
+        counts[holder] += 1;
+
+        let thrown_to = monkey.throws_to(&worry_level);
+
+        if thrown_to <= holder {
+            return Ok((thrown_to, worry_level));
+        }
+
+        holder = thrown_to;
+    }
+}
+
+/// Adds the inspections made on a single item over `rounds` to `counts`.
+fn fast_forward_item<W: Worry + Eq + Hash>(
+    monkeys: &[Monkey],
+    relief: Relief,
+    rounds: usize,
+    mut state: (usize, W),
+    counts: &mut [usize],
+) -> Result<(), EvalError> {
+    let mut seen = HashMap::new();
+    // Inspections made by each monkey before each round
+    let mut history = vec![vec![0; monkeys.len()]];
+
+    for round in 0..rounds {
+        if let Some(start) = seen.insert(state.clone(), round) {
+            let length = round - start;
+            let cycles = (rounds - round) / length;
+            let rest = (rounds - round) % length;
+
+            for (monkey, count) in counts.iter_mut().enumerate() {
+                let per_cycle = history[round][monkey] - history[start][monkey];
+
+                *count += history[round][monkey]
+                    + cycles * per_cycle
+                    + (history[start + rest][monkey] - history[start][monkey]);
+            }
+
+            return Ok(());
+        }
+
+        let mut current = history[round].clone();
+
+        state = play_item_round(monkeys, relief, state, &mut current)?;
+        history.push(current);
+    }
+
+    for (count, inspections) in counts.iter_mut().zip(&history[rounds]) {
+        *count += inspections;
+    }
+
+    Ok(())
+}
+
+/// Counts the inspections made by each monkey over `rounds`, following each
+/// item on its own. An item's path depends only on the monkey holding it and
+/// its worry level, so once that pair repeats the rest of its rounds follow
+/// the same cycle.
+pub fn fast_forward<W, F>(
+    monkeys: &[Monkey],
+    relief: Relief,
+    rounds: usize,
+    worry_level: F,
+) -> Result<Vec<usize>, EvalError>
+where
+    W: Worry + Eq + Hash,
+    F: Fn(u64) -> W,
+{
+    let mut counts = vec![0; monkeys.len()];
+
+    for (holder, monkey) in monkeys.iter().enumerate() {
+        for item in &monkey.items {
+            fast_forward_item(
+                monkeys,
+                relief,
+                rounds,
+                (holder, worry_level(*item)),
+                &mut counts,
+            )?;
+        }
+    }
+
+    Ok(counts)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{tests::example_monkeys, *};
+    use std::rc::Rc;
+
+    #[test]
+    fn matches_simulation() {
+        let monkeys = example_monkeys();
+
+        for (rounds, relief, numeric) in [
+            (20, Relief::DivideBy(3), Numeric::Native),
+            (20, Relief::DivideBy(3), Numeric::BigInt),
+            (20, Relief::None, Numeric::BigInt),
+            (1000, Relief::DivideBy(3), Numeric::Native),
+            (10000, Relief::None, Numeric::Residues),
+        ] {
+            assert_eq!(
+                count_inspections(&monkeys, rounds, relief, numeric, Strategy::FastForward),
+                count_inspections(&monkeys, rounds, relief, numeric, Strategy::Simulate),
+                "{} rounds with {:?} and {:?}",
+                rounds,
+                relief,
+                numeric
+            );
+        }
+
+        // Items enter cycles of 171 or 448 rounds after up to 175 rounds,
+        // so check before, at and between the cycle boundaries
+        let moduli: Rc<[u64]> = Rc::from(vec![23, 19, 13, 17]);
+        let residues = |item| Residues::new(Rc::clone(&moduli), item);
+        let mut simulation = Simulation::new(&monkeys, Relief::None, residues);
+
+        for rounds in [1, 2, 114, 171, 309, 448, 623, 624, 1000, 1337, 2500] {
+            simulation.play(rounds - simulation.round).unwrap();
+
+            assert_eq!(
+                fast_forward(&monkeys, Relief::None, rounds, residues),
+                Ok(simulation.inspect_counts.clone()),
+                "{} rounds",
+                rounds
+            );
+        }
+    }
+
+    #[test]
+    fn extrapolates_huge_round_counts() {
+        let monkeys = example_monkeys();
+
+        assert_eq!(
+            monkey_business(
+                &monkeys,
+                10000,
+                Relief::None,
+                Numeric::Residues,
+                Strategy::FastForward
+            ),
+            Ok(2713310158)
+        );
+
+        let counts = count_inspections(
+            &monkeys,
+            1_000_000_000,
+            Relief::None,
+            Numeric::Residues,
+            Strategy::FastForward,
+        )
+        .unwrap();
+
+        // Close to 100000 times the counts after 10000 rounds
+        assert_eq!(counts, vec![5217653496, 4782346500, 193256586, 5202028499]);
+
+        // The product no longer fits in a u64
+        assert_eq!(
+            monkey_business(
+                &monkeys,
+                1_000_000_000,
+                Relief::None,
+                Numeric::Residues,
+                Strategy::FastForward
+            ),
+            Ok(27142382184098982504)
+        );
+    }
+}
@@ -0,0 +1,336 @@
+use crate::worry::Worry;
+use aoc_common::ParseError;
+use std::fmt;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Op {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Remainder,
+}
+
+impl Op {
+    fn from_token(token: &str) -> Option<Self> {
+        Some(match token {
+            "+" => Op::Add,
+            "-" => Op::Subtract,
+            "*" => Op::Multiply,
+            "/" => Op::Divide,
+            "%" => Op::Remainder,
+            _ => return None,
+        })
+    }
+
+    fn precedence(&self) -> u8 {
+        match self {
+            Op::Add | Op::Subtract => 1,
+            Op::Multiply | Op::Divide | Op::Remainder => 2,
+        }
+    }
+
+    pub fn apply(&self, lhs: u128, rhs: u128) -> Result<u128, EvalError> {
+        match self {
+            Op::Add => lhs.checked_add(rhs).ok_or(EvalError::Overflow),
+            Op::Subtract => lhs.checked_sub(rhs).ok_or(EvalError::Underflow),
+            Op::Multiply => lhs.checked_mul(rhs).ok_or(EvalError::Overflow),
+            Op::Divide => lhs.checked_div(rhs).ok_or(EvalError::DivideByZero),
+            Op::Remainder => lhs.checked_rem(rhs).ok_or(EvalError::DivideByZero),
+        }
+    }
+}
+
+impl fmt::Display for Op {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Op::Add => "+",
+                Op::Subtract => "-",
+                Op::Multiply => "*",
+                Op::Divide => "/",
+                Op::Remainder => "%",
+            }
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum EvalError {
+    Overflow,
+    Underflow,
+    DivideByZero,
+    /// The worry level representation can't perform the operation.
+    Unsupported(Op),
+}
+
+impl fmt::Display for EvalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EvalError::Overflow => write!(f, "worry level overflowed"),
+            EvalError::Underflow => write!(f, "worry level went below zero"),
+            EvalError::DivideByZero => write!(f, "worry level divided by zero"),
+            EvalError::Unsupported(op) => {
+                write!(f, "worry levels can't be operated on with {}", op)
+            }
+        }
+    }
+}
+
+impl std::error::Error for EvalError {}
+
+/// An operation computing the new worry level from the `old` one.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum Expr {
+    #[default]
+    Old,
+    Literal(u64),
+    Binary(Box<Expr>, Op, Box<Expr>),
+}
+
+impl Expr {
+    pub fn evaluate<W: Worry>(&self, old: &W) -> Result<W, EvalError> {
+        match self {
+            Expr::Old => Ok(old.clone()),
+            Expr::Literal(value) => Ok(old.constant(*value)),
+            Expr::Binary(lhs, op, rhs) => lhs.evaluate(old)?.apply(*op, &rhs.evaluate(old)?),
+        }
+    }
+
+    /// How the worry level changes, phrased as in the puzzle's narration,
+    /// such as "is multiplied by 19".
+    pub fn describe(&self) -> String {
+        if let Expr::Binary(lhs, op, rhs) = self {
+            match (&**lhs, op, &**rhs) {
+                (Expr::Old, Op::Add, Expr::Literal(value)) => {
+                    return format!("increases by {}", value);
+                }
+                (Expr::Old, Op::Subtract, Expr::Literal(value)) => {
+                    return format!("decreases by {}", value);
+                }
+                (Expr::Old, Op::Multiply, Expr::Literal(value)) => {
+                    return format!("is multiplied by {}", value);
+                }
+                (Expr::Old, Op::Multiply, Expr::Old) => {
+                    return String::from("is multiplied by itself");
+                }
+                (Expr::Old, Op::Divide, Expr::Literal(value)) => {
+                    return format!("is divided by {}", value);
+                }
+                _ => {}
+            }
+        }
+
+        format!("becomes {}", self)
+    }
+}
+
+impl fmt::Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Expr::Old => write!(f, "old"),
+            Expr::Literal(value) => write!(f, "{}", value),
+            Expr::Binary(lhs, op, rhs) => {
+                for (side, expr) in [("", lhs), (" ", rhs)] {
+                    write!(f, "{}", side)?;
+
+                    // Keep the right side grouped too, as - / and % aren't associative
+                    let grouped = match &**expr {
+                        Expr::Binary(_, inner, _) => {
+                            inner.precedence() < op.precedence()
+                                || (side == " " && inner.precedence() == op.precedence())
+                        }
+                        _ => false,
+                    };
+
+                    if grouped {
+                        write!(f, "({})", expr)?;
+                    } else {
+                        write!(f, "{}", expr)?;
+                    }
+
+                    if side.is_empty() {
+                        write!(f, " {}", op)?;
+                    }
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
+
+fn tokenize(input: &str) -> Vec<&str> {
+    let mut tokens = vec![];
+    let mut chars = input.char_indices().peekable();
+
+    while let Some((start, c)) = chars.next() {
+        if c.is_whitespace() {
+            continue;
+        }
+
+        let mut end = start + c.len_utf8();
+
+        if c.is_ascii_alphanumeric() {
+            while let Some((index, next)) = chars.peek() {
+                if !next.is_ascii_alphanumeric() {
+                    break;
+                }
+
+                end = index + next.len_utf8();
+                chars.next();
+            }
+        }
+
+        tokens.push(&input[start..end]);
+    }
+
+    tokens
+}
+
+struct Parser<'a> {
+    tokens: Vec<&'a str>,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<&'a str> {
+        self.tokens.get(self.position).copied()
+    }
+
+    /// Parses operators binding at least as tightly as `precedence`.
+    fn expression(&mut self, precedence: u8) -> Result<Expr, ParseError> {
+        let mut lhs = self.value()?;
+
+        while let Some(op) = self.peek().and_then(Op::from_token) {
+            if op.precedence() < precedence {
+                break;
+            }
+
+            self.position += 1;
+
+            let rhs = self.expression(op.precedence() + 1)?;
+
+            lhs = Expr::Binary(Box::new(lhs), op, Box::new(rhs));
+        }
+
+        Ok(lhs)
+    }
+
+    fn value(&mut self) -> Result<Expr, ParseError> {
+        let Some(token) = self.peek() else {
+            return Err(ParseError::new("expected a value", ""));
+        };
+
+        self.position += 1;
+
+        match token {
+            "old" => Ok(Expr::Old),
+            "(" => {
+                let expr = self.expression(0)?;
+
+                match self.peek() {
+                    Some(")") => {
+                        self.position += 1;
+
+                        Ok(expr)
+                    }
+                    Some(other) => Err(ParseError::new("expected \")\"", other)),
+                    None => Err(ParseError::new("expected a closing \")\"", "")),
+                }
+            }
+            _ if token.starts_with(|c: char| c.is_ascii_digit()) => token
+                .parse()
+                .map(Expr::Literal)
+                .map_err(|_| ParseError::new("expected a number", token)),
+            _ => Err(ParseError::new(
+                "expected \"old\", a number or \"(\"",
+                token,
+            )),
+        }
+    }
+}
+
+/// Parses an expression of `old`, numbers and parentheses joined by
+/// `+ - * / %`, where `* / %` bind tighter than `+ -`.
+pub fn parse_expression(input: &str) -> Result<Expr, ParseError> {
+    let mut parser = Parser {
+        tokens: tokenize(input),
+        position: 0,
+    };
+    let expr = parser.expression(0)?;
+
+    match parser.peek() {
+        None => Ok(expr),
+        Some(token) => Err(ParseError::new(
+            "expected an operator of +, -, *, / or %",
+            token,
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn evaluate(input: &str, old: u128) -> Result<u128, EvalError> {
+        parse_expression(input).unwrap().evaluate(&old)
+    }
+
+    #[test]
+    fn evaluates() {
+        assert_eq!(evaluate("old * 19", 79), Ok(1501));
+        assert_eq!(evaluate("old * old", 79), Ok(6241));
+        assert_eq!(evaluate("old + 2 * 3", 1), Ok(7));
+        assert_eq!(evaluate("(old + 2) * 3", 1), Ok(9));
+        assert_eq!(evaluate("old - 4 - 3", 10), Ok(3));
+        assert_eq!(evaluate("old / 2 % 4", 21), Ok(2));
+        assert_eq!(evaluate("old - 11", 10), Err(EvalError::Underflow));
+        assert_eq!(evaluate("old % (old - 5)", 5), Err(EvalError::DivideByZero));
+        assert_eq!(evaluate("old * old", u128::MAX), Err(EvalError::Overflow));
+    }
+
+    #[test]
+    fn displays() {
+        for input in [
+            "old * 19",
+            "(old + 2) * 3",
+            "old - (4 - 3)",
+            "old + 2 * old",
+        ] {
+            assert_eq!(parse_expression(input).unwrap().to_string(), input);
+        }
+    }
+
+    #[test]
+    fn describes() {
+        let describe = |input| parse_expression(input).unwrap().describe();
+
+        assert_eq!(describe("old * 19"), "is multiplied by 19");
+        assert_eq!(describe("old + 6"), "increases by 6");
+        assert_eq!(describe("old * old"), "is multiplied by itself");
+        assert_eq!(describe("(old + 1) * 19"), "becomes (old + 1) * 19");
+    }
+
+    #[test]
+    fn errors() {
+        let message = |input| parse_expression(input).unwrap_err().to_string();
+
+        assert_eq!(
+            message("old ^ 19"),
+            "expected an operator of +, -, *, / or %, found \"^\""
+        );
+        assert_eq!(message("old *"), "expected a value");
+        assert_eq!(message("(old + 1"), "expected a closing \")\"");
+        assert_eq!(
+            message("old + new"),
+            "expected \"old\", a number or \"(\", found \"new\""
+        );
+        assert_eq!(
+            message("old 5"),
+            "expected an operator of +, -, *, / or %, found \"5\""
+        );
+    }
+}
@@ -0,0 +1,472 @@
+use aoc_common::{parse, Answer, ParseError, Solution, SolveError};
+use std::{collections::VecDeque, hash::Hash, rc::Rc, str::FromStr};
+
+mod bigint;
+mod cycle;
+mod expr;
+mod trace;
+mod validate;
+mod worry;
+
+pub use bigint::BigUint;
+pub use cycle::{fast_forward, Strategy};
+pub use expr::{parse_expression, EvalError, Expr, Op};
+pub use trace::{report_inspections, Inspection, Round};
+pub use validate::{validate, Field, Notes, ValidationError};
+pub use worry::{Numeric, Residues, Worry};
+
+#[derive(Clone, Debug, Default)]
+pub struct Monkey {
+    /// Worry levels of the items held before the first round.
+    pub items: Vec<u64>,
+    operation: Expr,
+    divisible_by: u64,
+    to_truthy: usize,
+    to_falsy: usize,
+}
+
+impl Monkey {
+    pub fn inspect_item<W: Worry>(&self, worry_level: &W) -> Result<W, EvalError> {
+        self.operation.evaluate(worry_level)
+    }
+
+    pub fn throws_to<W: Worry>(&self, worry_level: &W) -> usize {
+        if worry_level.is_divisible_by(self.divisible_by) {
+            self.to_truthy
+        } else {
+            self.to_falsy
+        }
+    }
+}
+
+/// How worry levels are reduced after each inspection.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Relief {
+    None,
+    DivideBy(u64),
+}
+
+impl Relief {
+    pub fn apply<W: Worry>(&self, worry_level: W) -> Result<W, EvalError> {
+        match self {
+            Relief::None => Ok(worry_level),
+            Relief::DivideBy(divisor) => {
+                worry_level.apply(Op::Divide, &worry_level.constant(*divisor))
+            }
+        }
+    }
+}
+
+/// Monkeys playing keep away, with the items each holds and the number of
+/// inspections each has made, indexed by monkey number.
+#[derive(Clone, Debug)]
+pub struct Simulation<'m, W> {
+    monkeys: &'m [Monkey],
+    relief: Relief,
+    /// The number of rounds played so far.
+    pub round: usize,
+    pub items: Vec<VecDeque<W>>,
+    pub inspect_counts: Vec<usize>,
+}
+
+impl<'m, W: Worry> Simulation<'m, W> {
+    pub fn new<F>(monkeys: &'m [Monkey], relief: Relief, worry_level: F) -> Self
+    where
+        F: Fn(u64) -> W,
+    {
+        Simulation {
+            monkeys,
+            relief,
+            round: 0,
+            items: monkeys
+                .iter()
+                .map(|monkey| monkey.items.iter().map(|item| worry_level(*item)).collect())
+                .collect(),
+            inspect_counts: vec![0; monkeys.len()],
+        }
+    }
+
+    /// Plays a round, recording every inspection into `trace` if given.
+    fn play_round_into(
+        &mut self,
+        mut trace: Option<&mut Vec<Inspection<W>>>,
+    ) -> Result<(), EvalError> {
+        for (i, monkey) in self.monkeys.iter().enumerate() {
+            while let Some(item) = self.items[i].pop_front() {
+                let mut worry_level = monkey.inspect_item(&item)?;
+                let inspected = trace.is_some().then(|| worry_level.clone());
+
+                worry_level = self.relief.apply(worry_level)?;
+
+                let thrown_to = monkey.throws_to(&worry_level);
+
+                if let (Some(trace), Some(inspected)) = (trace.as_mut(), inspected) {
+                    trace.push(Inspection {
+                        monkey: i,
+                        item,
+                        inspected,
+                        relieved: worry_level.clone(),
+                        divisible: worry_level.is_divisible_by(monkey.divisible_by),
+                        thrown_to,
+                    });
+                }
+
+                self.inspect_counts[i] += 1;
+                self.items[thrown_to].push_back(worry_level);
+            }
+        }
+
+        self.round += 1;
+
+        Ok(())
+    }
+
+    pub fn play_round(&mut self) -> Result<(), EvalError> {
+        self.play_round_into(None)
+    }
+
+    pub fn play(&mut self, rounds: usize) -> Result<(), EvalError> {
+        for _ in 0..rounds {
+            self.play_round()?;
+        }
+
+        Ok(())
+    }
+
+    pub fn monkey_business(&self) -> u128 {
+        calculate_monkey_business(&self.inspect_counts)
+    }
+}
+
+/// Product of the two highest inspection counts, widened as counts after
+/// billions of rounds multiply past `u64`.
+pub fn calculate_monkey_business(inspect_counts: &[usize]) -> u128 {
+    let mut counts = inspect_counts.to_vec();
+
+    counts.sort_unstable_by(|a, b| b.cmp(a));
+    counts.iter().take(2).map(|count| *count as u128).product()
+}
+
+/// Plays the rounds with worry levels represented as chosen, returning
+/// the number of inspections made by each monkey.
+pub fn count_inspections(
+    monkeys: &[Monkey],
+    rounds: usize,
+    relief: Relief,
+    numeric: Numeric,
+    strategy: Strategy,
+) -> Result<Vec<usize>, EvalError> {
+    fn play<W, F>(
+        monkeys: &[Monkey],
+        rounds: usize,
+        relief: Relief,
+        strategy: Strategy,
+        worry_level: F,
+    ) -> Result<Vec<usize>, EvalError>
+    where
+        W: Worry + Eq + Hash,
+        F: Fn(u64) -> W,
+    {
+        match strategy {
+            Strategy::Simulate => {
+                let mut simulation = Simulation::new(monkeys, relief, worry_level);
+
+                simulation.play(rounds)?;
+
+                Ok(simulation.inspect_counts)
+            }
+            Strategy::FastForward => fast_forward(monkeys, relief, rounds, worry_level),
+        }
+    }
+
+    match numeric {
+        Numeric::Native => play(monkeys, rounds, relief, strategy, u128::from),
+        Numeric::BigInt => play(monkeys, rounds, relief, strategy, BigUint::new),
+        Numeric::Residues => {
+            let moduli: Rc<[u64]> = monkeys.iter().map(|m| m.divisible_by).collect();
+
+            play(monkeys, rounds, relief, strategy, |item| {
+                Residues::new(Rc::clone(&moduli), item)
+            })
+        }
+    }
+}
+
+pub fn monkey_business(
+    monkeys: &[Monkey],
+    rounds: usize,
+    relief: Relief,
+    numeric: Numeric,
+    strategy: Strategy,
+) -> Result<u128, EvalError> {
+    count_inspections(monkeys, rounds, relief, numeric, strategy)
+        .map(|inspect_counts| calculate_monkey_business(&inspect_counts))
+}
+
+fn parse_number<T: FromStr>(data: &str, line: &str) -> Result<T, ParseError> {
+    parse::integers(line)
+        .map_err(|e| e.locate(data, line))?
+        .into_iter()
+        .next()
+        .ok_or_else(|| ParseError::at(data, line, "expected a number"))
+}
+
+fn parse_operation(line: &str) -> Result<Expr, ParseError> {
+    let expression = line
+        .strip_prefix("Operation:")
+        .map(str::trim_start)
+        .and_then(|rest| rest.strip_prefix("new"))
+        .map(str::trim_start)
+        .and_then(|rest| rest.strip_prefix('='))
+        .ok_or_else(|| ParseError::new("expected \"Operation: new = <expression>\"", line))?;
+
+    parse_expression(expression)
+}
+
+fn parse_monkeys(data: &str) -> Result<Vec<Monkey>, ParseError> {
+    let mut notes = vec![];
+    // The header and field lines of each monkey, to locate validation errors
+    let mut lines = vec![];
+
+    for chunk in parse::blocks(data) {
+        let mut chunk_lines = parse::lines(chunk).map(str::trim);
+        let header = chunk_lines.next().unwrap_or(chunk);
+        let mut monkey_notes = Notes {
+            number: header
+                .strip_prefix("Monkey")
+                .and_then(|rest| rest.strip_suffix(':'))
+                .and_then(|number| number.trim().parse().ok()),
+            ..Notes::default()
+        };
+        let mut fields = vec![];
+
+        for line in chunk_lines {
+            let field = Field::ALL
+                .into_iter()
+                .find(|field| line.starts_with(field.prefix()))
+                .ok_or_else(|| ParseError::at(data, line, "expected a monkey's field"))?;
+            let monkey = &mut monkey_notes.monkey;
+
+            match field {
+                Field::Items => monkey
+                    .items
+                    .extend(parse::integers::<u64>(line).map_err(|e| e.locate(data, line))?),
+                Field::Operation => {
+                    monkey.operation = parse_operation(line).map_err(|e| e.locate(data, line))?;
+                }
+                Field::Test => monkey.divisible_by = parse_number(data, line)?,
+                Field::IfTrue => monkey.to_truthy = parse_number(data, line)?,
+                Field::IfFalse => monkey.to_falsy = parse_number(data, line)?,
+            }
+
+            monkey_notes.fields.push(field);
+            fields.push((field, line));
+        }
+
+        notes.push(monkey_notes);
+        lines.push((header, fields));
+    }
+
+    // Every problem is found, but only the first can be located
+    validate(&notes).map_err(|errors| {
+        let error = &errors[0];
+        let (header, fields) = &lines[error.monkey()];
+        let line = fields
+            .iter()
+            .find(|(field, _)| Some(*field) == error.field())
+            .map_or(*header, |(_, line)| *line);
+        let message = match errors.len() - 1 {
+            0 => error.to_string(),
+            1 => format!("{} (and 1 more problem)", error),
+            more => format!("{} (and {} more problems)", error, more),
+        };
+
+        ParseError::at(data, line, message)
+    })?;
+
+    Ok(notes.into_iter().map(|notes| notes.monkey).collect())
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input<'a> = Vec<Monkey>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        parse_monkeys(input)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        monkey_business(
+            input,
+            20,
+            Relief::DivideBy(3),
+            Numeric::Native,
+            Strategy::Simulate,
+        )
+        .map_err(|error| SolveError::new(error.to_string()))
+        .and_then(|value| {
+            Answer::try_from(value)
+                .map_err(|_| SolveError::new("monkey business exceeds an integer answer"))
+        })
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        monkey_business(
+            input,
+            10000,
+            Relief::None,
+            Numeric::Residues,
+            Strategy::FastForward,
+        )
+        .map_err(|error| SolveError::new(error.to_string()))
+        .and_then(|value| {
+            Answer::try_from(value)
+                .map_err(|_| SolveError::new("monkey business exceeds an integer answer"))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::examples!(Day11, "day-11", {
+        example: { part_1: 10605, part_2: 2713310158u64 },
+    });
+
+    pub(crate) const EXAMPLE: &str = include_str!("../../../examples/day-11/example.txt");
+
+    /// The example's monkeys, shared by the tests of every module.
+    pub(crate) fn example_monkeys() -> Vec<Monkey> {
+        parse_monkeys(EXAMPLE).unwrap()
+    }
+
+    #[test]
+    fn works() {
+        let monkeys = example_monkeys();
+        let mut simulation = Simulation::new(&monkeys, Relief::DivideBy(3), u128::from);
+
+        simulation.play(20).unwrap();
+
+        assert_eq!(simulation.inspect_counts, vec![101, 95, 7, 105]);
+        assert_eq!(simulation.monkey_business(), 10605);
+    }
+
+    #[test]
+    fn works_big() {
+        let monkeys = example_monkeys();
+
+        assert_eq!(
+            monkey_business(
+                &monkeys,
+                10000,
+                Relief::None,
+                Numeric::Residues,
+                Strategy::Simulate
+            ),
+            Ok(2713310158)
+        );
+    }
+
+    #[test]
+    fn numeric_types_agree() {
+        let monkeys = example_monkeys();
+
+        for numeric in [Numeric::Native, Numeric::BigInt, Numeric::Residues] {
+            assert_eq!(
+                count_inspections(&monkeys, 1, Relief::None, numeric, Strategy::Simulate),
+                Ok(vec![2, 4, 3, 6])
+            );
+        }
+
+        assert_eq!(
+            count_inspections(
+                &monkeys,
+                20,
+                Relief::DivideBy(3),
+                Numeric::BigInt,
+                Strategy::Simulate
+            ),
+            Ok(vec![101, 95, 7, 105])
+        );
+        assert_eq!(
+            count_inspections(
+                &monkeys,
+                20,
+                Relief::None,
+                Numeric::BigInt,
+                Strategy::Simulate
+            ),
+            Ok(vec![99, 97, 8, 103])
+        );
+        assert_eq!(
+            count_inspections(
+                &monkeys,
+                20,
+                Relief::None,
+                Numeric::Residues,
+                Strategy::Simulate
+            ),
+            Ok(vec![99, 97, 8, 103])
+        );
+
+        // Squaring quickly outgrows native integers without relief
+        assert_eq!(
+            count_inspections(
+                &monkeys,
+                10000,
+                Relief::None,
+                Numeric::Native,
+                Strategy::Simulate
+            ),
+            Err(EvalError::Overflow)
+        );
+        assert_eq!(
+            count_inspections(
+                &monkeys,
+                1,
+                Relief::DivideBy(3),
+                Numeric::Residues,
+                Strategy::Simulate
+            ),
+            Err(EvalError::Unsupported(Op::Divide))
+        );
+    }
+
+    #[test]
+    fn unknown_operator() {
+        let data = EXAMPLE.replace("old * 19", "old ^ 19");
+
+        assert_eq!(
+            parse_monkeys(&data).unwrap_err().to_string(),
+            "line 3, column 24: expected an operator of +, -, *, / or %, found \"^\""
+        );
+    }
+
+    #[test]
+    fn variant_operations() {
+        let data = EXAMPLE
+            .replace("old * 19", "(old + 1) * 19 % 1000")
+            .replace("old * old", "old * old - old");
+        let monkeys = parse_monkeys(&data).unwrap();
+
+        assert_eq!(monkeys[0].operation.to_string(), "(old + 1) * 19 % 1000");
+        assert_eq!(monkeys[0].inspect_item(&79u128), Ok(520));
+        assert_eq!(monkeys[2].inspect_item(&79u128), Ok(6162));
+
+        let data = EXAMPLE.replace("old + 6", "old - 100");
+        let monkeys = parse_monkeys(&data).unwrap();
+
+        assert_eq!(
+            monkey_business(
+                &monkeys,
+                20,
+                Relief::DivideBy(3),
+                Numeric::Native,
+                Strategy::Simulate
+            ),
+            Err(EvalError::Underflow)
+        );
+    }
+}
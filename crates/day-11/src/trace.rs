@@ -0,0 +1,209 @@
+use crate::{EvalError, Relief, Simulation, Worry};
+use std::fmt::Display;
+
+/// An item inspected by a monkey and the worry levels it went through
+/// before being thrown.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Inspection<W> {
+    pub monkey: usize,
+    /// The worry level when picked up.
+    pub item: W,
+    /// The worry level after the monkey's operation.
+    pub inspected: W,
+    /// The worry level after relief, as thrown.
+    pub relieved: W,
+    pub divisible: bool,
+    pub thrown_to: usize,
+}
+
+/// The inspections made during a round, and the state of the monkeys after it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Round<W> {
+    /// The round number, starting from 1.
+    pub number: usize,
+    pub inspections: Vec<Inspection<W>>,
+    pub items: Vec<Vec<W>>,
+    pub inspect_counts: Vec<usize>,
+}
+
+impl<'m, W: Worry> Simulation<'m, W> {
+    pub fn trace_round(&mut self) -> Result<Round<W>, EvalError> {
+        let mut inspections = vec![];
+
+        self.play_round_into(Some(&mut inspections))?;
+
+        Ok(Round {
+            number: self.round,
+            inspections,
+            items: self
+                .items
+                .iter()
+                .map(|items| items.iter().cloned().collect())
+                .collect(),
+            inspect_counts: self.inspect_counts.clone(),
+        })
+    }
+
+    pub fn trace(&mut self, rounds: usize) -> Result<Vec<Round<W>>, EvalError> {
+        (0..rounds).map(|_| self.trace_round()).collect()
+    }
+}
+
+impl<'m, W: Worry + Display> Simulation<'m, W> {
+    /// Describes a round in the words of the puzzle, inspection by inspection.
+    pub fn narrate(&self, round: &Round<W>) -> Vec<String> {
+        let mut lines = vec![];
+        let mut current = None;
+
+        for inspection in &round.inspections {
+            let monkey = &self.monkeys[inspection.monkey];
+
+            if current != Some(inspection.monkey) {
+                current = Some(inspection.monkey);
+                lines.push(format!("Monkey {}:", inspection.monkey));
+            }
+
+            lines.push(format!(
+                "  Monkey inspects an item with a worry level of {}.",
+                inspection.item
+            ));
+            lines.push(format!(
+                "    Worry level {} to {}.",
+                monkey.operation.describe(),
+                inspection.inspected
+            ));
+
+            if let Relief::DivideBy(divisor) = self.relief {
+                lines.push(format!(
+                    "    Monkey gets bored with item. Worry level is divided by {} to {}.",
+                    divisor, inspection.relieved
+                ));
+            }
+
+            lines.push(format!(
+                "    Current worry level {} divisible by {}.",
+                if inspection.divisible { "is" } else { "is not" },
+                monkey.divisible_by
+            ));
+            lines.push(format!(
+                "    Item with worry level {} is thrown to monkey {}.",
+                inspection.relieved, inspection.thrown_to
+            ));
+        }
+
+        lines.push(String::new());
+        lines.push(format!(
+            "After round {}, the monkeys are holding items with these worry levels:",
+            round.number
+        ));
+
+        for (monkey, items) in round.items.iter().enumerate() {
+            let items = items
+                .iter()
+                .map(|item| item.to_string())
+                .collect::<Vec<_>>();
+
+            lines.push(
+                format!("Monkey {}: {}", monkey, items.join(", "))
+                    .trim_end()
+                    .to_owned(),
+            );
+        }
+
+        lines
+    }
+}
+
+/// Lists the number of times each monkey inspected items.
+pub fn report_inspections(inspect_counts: &[usize]) -> Vec<String> {
+    inspect_counts
+        .iter()
+        .enumerate()
+        .map(|(monkey, count)| format!("Monkey {} inspected items {} times.", monkey, count))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{tests::example_monkeys, *};
+
+    #[test]
+    fn narrates_first_round() {
+        let monkeys = example_monkeys();
+        let mut simulation = Simulation::new(&monkeys, Relief::DivideBy(3), u128::from);
+        let round = simulation.trace_round().unwrap();
+        let lines = simulation.narrate(&round);
+
+        assert_eq!(
+            lines[..7],
+            [
+                "Monkey 0:",
+                "  Monkey inspects an item with a worry level of 79.",
+                "    Worry level is multiplied by 19 to 1501.",
+                "    Monkey gets bored with item. Worry level is divided by 3 to 500.",
+                "    Current worry level is not divisible by 23.",
+                "    Item with worry level 500 is thrown to monkey 3.",
+                "  Monkey inspects an item with a worry level of 98.",
+            ]
+        );
+        assert_eq!(
+            lines[lines.len() - 5..],
+            [
+                "After round 1, the monkeys are holding items with these worry levels:",
+                "Monkey 0: 20, 23, 27, 26",
+                "Monkey 1: 2080, 25, 167, 207, 401, 1046",
+                "Monkey 2:",
+                "Monkey 3:",
+            ]
+        );
+        assert_eq!(round.inspections.len(), 14);
+        assert_eq!(round.inspect_counts, vec![2, 4, 3, 5]);
+        assert_eq!(
+            round.inspections.iter().find(|i| i.divisible).unwrap(),
+            &Inspection {
+                monkey: 2,
+                item: 79,
+                inspected: 6241,
+                relieved: 2080,
+                divisible: true,
+                thrown_to: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn traces_rounds() {
+        let monkeys = example_monkeys();
+        let mut simulation = Simulation::new(&monkeys, Relief::DivideBy(3), u128::from);
+        let rounds = simulation.trace(20).unwrap();
+
+        assert_eq!(rounds.len(), 20);
+        assert_eq!(rounds[19].number, 20);
+        assert_eq!(
+            rounds[19].items,
+            vec![
+                vec![10, 12, 14, 26, 34],
+                vec![245, 93, 53, 199, 115],
+                vec![],
+                vec![]
+            ]
+        );
+        assert_eq!(
+            report_inspections(&rounds[19].inspect_counts),
+            [
+                "Monkey 0 inspected items 101 times.",
+                "Monkey 1 inspected items 95 times.",
+                "Monkey 2 inspected items 7 times.",
+                "Monkey 3 inspected items 105 times.",
+            ]
+        );
+
+        // Tracing doesn't change how the monkeys play
+        let mut untraced = Simulation::new(&monkeys, Relief::DivideBy(3), u128::from);
+
+        untraced.play(20).unwrap();
+
+        assert_eq!(untraced.items, simulation.items);
+        assert_eq!(untraced.inspect_counts, rounds[19].inspect_counts);
+    }
+}
@@ -0,0 +1,315 @@
+use crate::Monkey;
+use std::fmt;
+
+/// A line of a monkey's notes.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Field {
+    Items,
+    Operation,
+    Test,
+    IfTrue,
+    IfFalse,
+}
+
+impl Field {
+    pub const ALL: [Field; 5] = [
+        Field::Items,
+        Field::Operation,
+        Field::Test,
+        Field::IfTrue,
+        Field::IfFalse,
+    ];
+
+    pub fn prefix(&self) -> &'static str {
+        match self {
+            Field::Items => "Starting items:",
+            Field::Operation => "Operation:",
+            Field::Test => "Test:",
+            Field::IfTrue => "If true:",
+            Field::IfFalse => "If false:",
+        }
+    }
+}
+
+/// Notes that would make the monkeys misbehave: panic dividing by zero,
+/// throw to a monkey that doesn't exist, or juggle an item forever. Throws
+/// go by position, so each header must also number its monkey in order.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ValidationError {
+    /// The header isn't `Monkey N:` for the monkey's position, with the
+    /// number it gives if any.
+    Misnumbered {
+        monkey: usize,
+        found: Option<usize>,
+    },
+    MissingField {
+        monkey: usize,
+        field: Field,
+    },
+    ZeroDivisor {
+        monkey: usize,
+    },
+    TargetOutOfRange {
+        monkey: usize,
+        field: Field,
+        target: usize,
+        monkeys: usize,
+    },
+    ThrowsToItself {
+        monkey: usize,
+        field: Field,
+    },
+}
+
+impl ValidationError {
+    pub fn monkey(&self) -> usize {
+        match self {
+            ValidationError::Misnumbered { monkey, .. }
+            | ValidationError::MissingField { monkey, .. }
+            | ValidationError::ZeroDivisor { monkey }
+            | ValidationError::TargetOutOfRange { monkey, .. }
+            | ValidationError::ThrowsToItself { monkey, .. } => *monkey,
+        }
+    }
+
+    /// The line of the monkey's notes at fault, or `None` for the header.
+    pub fn field(&self) -> Option<Field> {
+        match self {
+            ValidationError::Misnumbered { .. } | ValidationError::MissingField { .. } => None,
+            ValidationError::TargetOutOfRange { field, .. }
+            | ValidationError::ThrowsToItself { field, .. } => Some(*field),
+            ValidationError::ZeroDivisor { .. } => Some(Field::Test),
+        }
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidationError::Misnumbered { monkey, .. } => {
+                write!(f, "expected the header \"Monkey {}:\"", monkey)
+            }
+            ValidationError::MissingField { monkey, field } => {
+                write!(f, "monkey {} is missing \"{}\"", monkey, field.prefix())
+            }
+            ValidationError::ZeroDivisor { monkey } => {
+                write!(f, "monkey {} tests divisibility by zero", monkey)
+            }
+            ValidationError::TargetOutOfRange {
+                monkey,
+                target,
+                monkeys,
+                ..
+            } => write!(
+                f,
+                "monkey {} throws to monkey {}, but there are only {} monkeys",
+                monkey, target, monkeys
+            ),
+            ValidationError::ThrowsToItself { monkey, .. } => {
+                write!(f, "monkey {} throws to itself", monkey)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+/// A monkey's notes as written, before they are checked.
+#[derive(Clone, Debug, Default)]
+pub struct Notes {
+    /// The number given by the `Monkey N:` header, if it has one.
+    pub number: Option<usize>,
+    /// The fields that were given, in order.
+    pub fields: Vec<Field>,
+    /// The monkey described, with defaults in place of missing fields.
+    pub monkey: Monkey,
+}
+
+/// Checks that every monkey can be simulated, returning every problem found
+/// in the order of the notes.
+pub fn validate(notes: &[Notes]) -> Result<(), Vec<ValidationError>> {
+    let monkeys = notes.len();
+    let mut errors = vec![];
+
+    for (index, notes) in notes.iter().enumerate() {
+        let monkey = &notes.monkey;
+
+        if notes.number != Some(index) {
+            errors.push(ValidationError::Misnumbered {
+                monkey: index,
+                found: notes.number,
+            });
+        }
+
+        for field in Field::ALL {
+            if !notes.fields.contains(&field) {
+                errors.push(ValidationError::MissingField {
+                    monkey: index,
+                    field,
+                });
+            }
+        }
+
+        if notes.fields.contains(&Field::Test) && monkey.divisible_by == 0 {
+            errors.push(ValidationError::ZeroDivisor { monkey: index });
+        }
+
+        for (field, target) in [
+            (Field::IfTrue, monkey.to_truthy),
+            (Field::IfFalse, monkey.to_falsy),
+        ] {
+            if !notes.fields.contains(&field) {
+                continue;
+            }
+
+            if target >= monkeys {
+                errors.push(ValidationError::TargetOutOfRange {
+                    monkey: index,
+                    field,
+                    target,
+                    monkeys,
+                });
+            } else if target == index {
+                errors.push(ValidationError::ThrowsToItself {
+                    monkey: index,
+                    field,
+                });
+            }
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        tests::{example_monkeys, EXAMPLE},
+        *,
+    };
+
+    fn message(data: &str) -> String {
+        parse_monkeys(data).unwrap_err().to_string()
+    }
+
+    /// Complete notes for each of the example's monkeys.
+    fn example_notes() -> Vec<Notes> {
+        example_monkeys()
+            .into_iter()
+            .enumerate()
+            .map(|(number, monkey)| Notes {
+                number: Some(number),
+                fields: Field::ALL.to_vec(),
+                monkey,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn accepts_example() {
+        assert_eq!(validate(&example_notes()), Ok(()));
+    }
+
+    #[test]
+    fn missing_fields() {
+        assert_eq!(
+            message(&EXAMPLE.replace("  Test: divisible by 13\n", "")),
+            "line 15: monkey 2 is missing \"Test:\", found \"Monkey 2:\""
+        );
+        assert_eq!(
+            message(&EXAMPLE.replace("  Operation: new = old * 19\n", "")),
+            "line 1: monkey 0 is missing \"Operation:\", found \"Monkey 0:\""
+        );
+    }
+
+    #[test]
+    fn misnumbered_headers() {
+        assert_eq!(
+            message(&EXAMPLE.replace("Monkey 2:", "Monkey 3:")),
+            "line 15: expected the header \"Monkey 2:\", found \"Monkey 3:\""
+        );
+        assert_eq!(
+            message(&EXAMPLE.replace("Monkey 1:", "Monkey one:")),
+            "line 8: expected the header \"Monkey 1:\", found \"Monkey one:\""
+        );
+
+        let error = ValidationError::Misnumbered {
+            monkey: 2,
+            found: Some(3),
+        };
+
+        assert_eq!(error.monkey(), 2);
+        assert_eq!(error.field(), None);
+    }
+
+    #[test]
+    fn unknown_lines() {
+        assert_eq!(
+            message(&EXAMPLE.replace("  Test: divisible by 19", "  Tset: divisible by 19")),
+            "line 11, column 3: expected a monkey's field, found \"Tset: divisible by 19\""
+        );
+    }
+
+    #[test]
+    fn invalid_monkeys() {
+        assert_eq!(
+            message(&EXAMPLE.replace("divisible by 17", "divisible by 0")),
+            "line 25, column 3: monkey 3 tests divisibility by zero, found \"Test: divisible by 0\""
+        );
+        assert_eq!(
+            message(&EXAMPLE.replace("If true: throw to monkey 1", "If true: throw to monkey 7")),
+            "line 19, column 5: monkey 2 throws to monkey 7, but there are only 4 monkeys, found \"If true: throw to monkey 7\""
+        );
+        assert_eq!(
+            message(&EXAMPLE.replace("If false: throw to monkey 0", "If false: throw to monkey 1")),
+            "line 13, column 5: monkey 1 throws to itself, found \"If false: throw to monkey 1\""
+        );
+        assert_eq!(
+            message(
+                &EXAMPLE
+                    .replace("Monkey 1:", "Monkey 5:")
+                    .replace("divisible by 17", "divisible by 0")
+            ),
+            "line 8: expected the header \"Monkey 1:\" (and 1 more problem), found \"Monkey 5:\""
+        );
+    }
+
+    #[test]
+    fn structured_errors() {
+        let mut notes = example_notes();
+
+        notes[3].monkey.to_falsy = 4;
+        notes[0].monkey.divisible_by = 0;
+        notes[1].number = None;
+        notes[2].fields.retain(|field| *field != Field::Items);
+
+        let errors = validate(&notes).unwrap_err();
+
+        assert_eq!(
+            errors,
+            vec![
+                ValidationError::ZeroDivisor { monkey: 0 },
+                ValidationError::Misnumbered {
+                    monkey: 1,
+                    found: None,
+                },
+                ValidationError::MissingField {
+                    monkey: 2,
+                    field: Field::Items,
+                },
+                ValidationError::TargetOutOfRange {
+                    monkey: 3,
+                    field: Field::IfFalse,
+                    target: 4,
+                    monkeys: 4,
+                },
+            ]
+        );
+        assert_eq!(errors[0].field(), Some(Field::Test));
+        assert_eq!(errors[2].field(), None);
+    }
+}
@@ -0,0 +1,153 @@
+use crate::expr::{EvalError, Op};
+use std::{fmt, rc::Rc, str::FromStr};
+
+/// A representation of an item's worry level that monkeys can operate on.
+pub trait Worry: Clone + fmt::Debug {
+    /// A constant, in the same representation as this value.
+    fn constant(&self, value: u64) -> Self;
+
+    fn apply(&self, op: Op, rhs: &Self) -> Result<Self, EvalError>;
+
+    fn is_divisible_by(&self, divisor: u64) -> bool;
+}
+
+/// Native integers, which error when an operation overflows.
+impl Worry for u128 {
+    fn constant(&self, value: u64) -> Self {
+        value as u128
+    }
+
+    fn apply(&self, op: Op, rhs: &Self) -> Result<Self, EvalError> {
+        op.apply(*self, *rhs)
+    }
+
+    fn is_divisible_by(&self, divisor: u64) -> bool {
+        divisor != 0 && self.is_multiple_of(divisor as u128)
+    }
+}
+
+/// A worry level tracked only by its remainder modulo each monkey's divisor,
+/// which never grows, but can't be divided.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Residues {
+    moduli: Rc<[u64]>,
+    values: Vec<u64>,
+}
+
+impl Residues {
+    pub fn new(moduli: Rc<[u64]>, value: u64) -> Self {
+        Residues {
+            values: moduli.iter().map(|modulus| value % modulus).collect(),
+            moduli,
+        }
+    }
+
+    pub fn moduli(&self) -> &[u64] {
+        &self.moduli
+    }
+
+    pub fn values(&self) -> &[u64] {
+        &self.values
+    }
+}
+
+impl Worry for Residues {
+    fn constant(&self, value: u64) -> Self {
+        Residues::new(Rc::clone(&self.moduli), value)
+    }
+
+    fn apply(&self, op: Op, rhs: &Self) -> Result<Self, EvalError> {
+        let combine = |a: u64, b: u64, modulus: u64| -> u64 {
+            let (a, b, modulus) = (a as u128, b as u128, modulus as u128);
+
+            (match op {
+                Op::Add => (a + b) % modulus,
+                // Wraps within the modulus, as the true value isn't known
+                Op::Subtract => (a + modulus - b) % modulus,
+                _ => a * b % modulus,
+            }) as u64
+        };
+
+        if matches!(op, Op::Divide | Op::Remainder) {
+            return Err(EvalError::Unsupported(op));
+        }
+
+        Ok(Residues {
+            values: self
+                .values
+                .iter()
+                .zip(&rhs.values)
+                .zip(self.moduli.iter())
+                .map(|((a, b), modulus)| combine(*a, *b, *modulus))
+                .collect(),
+            moduli: Rc::clone(&self.moduli),
+        })
+    }
+
+    fn is_divisible_by(&self, divisor: u64) -> bool {
+        let index = self
+            .moduli
+            .iter()
+            .position(|modulus| *modulus == divisor)
+            .unwrap_or_else(|| panic!("Residues are not tracked modulo {}.", divisor));
+
+        self.values[index] == 0
+    }
+}
+
+/// The worry level representation to simulate with, chosen at runtime.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Numeric {
+    /// 128-bit integers, erroring on overflow.
+    #[default]
+    Native,
+    /// Arbitrary precision integers, which never overflow but may grow slowly.
+    BigInt,
+    /// Remainders modulo each monkey's divisor, which can't be divided.
+    Residues,
+}
+
+impl FromStr for Numeric {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, String> {
+        match s {
+            "native" => Ok(Numeric::Native),
+            "big" | "bigint" => Ok(Numeric::BigInt),
+            "residues" | "rns" => Ok(Numeric::Residues),
+            _ => Err(format!(
+                "Unknown numeric type {}, expected native, bigint or residues",
+                s
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn residues() {
+        let moduli: Rc<[u64]> = Rc::from(vec![3, 5, 7]);
+        let value = Residues::new(moduli, 79);
+
+        assert_eq!(value.values(), &[1, 4, 2]);
+
+        let squared = value.apply(Op::Multiply, &value).unwrap();
+
+        assert_eq!(squared, value.constant(79 * 79));
+        assert!(squared
+            .apply(Op::Add, &value.constant(2))
+            .unwrap()
+            .is_divisible_by(3));
+        assert_eq!(
+            value.apply(Op::Subtract, &value.constant(80)).unwrap(),
+            value.constant(3 * 5 * 7 - 1)
+        );
+        assert_eq!(
+            value.apply(Op::Divide, &value.constant(2)),
+            Err(EvalError::Unsupported(Op::Divide))
+        );
+    }
+}
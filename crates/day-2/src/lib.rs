@@ -0,0 +1,170 @@
+use aoc_common::{parse, Answer, ParseError, Solution, SolveError};
+
+#[derive(Debug)]
+enum Outcome {
+    Loss,
+    Draw,
+    Win,
+}
+
+impl Outcome {
+    // part 2
+    pub fn from(value: &str) -> Result<Self, ParseError> {
+        match value {
+            "X" => Ok(Outcome::Loss),
+            "Y" => Ok(Outcome::Draw),
+            "Z" => Ok(Outcome::Win),
+            _ => Err(ParseError::new("expected an outcome of X, Y or Z", value)),
+        }
+    }
+
+    pub fn points(&self) -> u32 {
+        match self {
+            Outcome::Loss => 0,
+            Outcome::Draw => 3,
+            Outcome::Win => 6,
+        }
+    }
+
+    pub fn infer_hand(&self, opponent: Hand) -> Hand {
+        match self {
+            Outcome::Loss => match opponent {
+                Hand::Rock => Hand::Scissors,
+                Hand::Paper => Hand::Rock,
+                Hand::Scissors => Hand::Paper,
+            },
+            Outcome::Draw => opponent.clone(),
+            Outcome::Win => match opponent {
+                Hand::Rock => Hand::Paper,
+                Hand::Paper => Hand::Scissors,
+                Hand::Scissors => Hand::Rock,
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Hand {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl Hand {
+    // part 1
+    pub fn from(value: &str) -> Result<Self, ParseError> {
+        match value {
+            "A" | "X" => Ok(Hand::Rock),
+            "B" | "Y" => Ok(Hand::Paper),
+            "C" | "Z" => Ok(Hand::Scissors),
+            _ => Err(ParseError::new(
+                "expected a hand of A, B, C, X, Y or Z",
+                value,
+            )),
+        }
+    }
+
+    pub fn points(&self) -> u32 {
+        match self {
+            Hand::Rock => 1,
+            Hand::Paper => 2,
+            Hand::Scissors => 3,
+        }
+    }
+
+    pub fn play(&self, opponent: Hand) -> Outcome {
+        match self {
+            Hand::Rock => match opponent {
+                Hand::Rock => Outcome::Draw,
+                Hand::Paper => Outcome::Loss,
+                Hand::Scissors => Outcome::Win,
+            },
+            Hand::Paper => match opponent {
+                Hand::Rock => Outcome::Win,
+                Hand::Paper => Outcome::Draw,
+                Hand::Scissors => Outcome::Loss,
+            },
+            Hand::Scissors => match opponent {
+                Hand::Rock => Outcome::Loss,
+                Hand::Paper => Outcome::Win,
+                Hand::Scissors => Outcome::Draw,
+            },
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Round {
+    opponent: Hand,
+    // part 1
+    response: Hand,
+    // part 2
+    outcome: Outcome,
+}
+
+fn parse_rounds(data: &str) -> Result<Vec<Round>, ParseError> {
+    parse::lines(data)
+        .map(|row| {
+            let mut parts = row.split_whitespace();
+
+            match (parts.next(), parts.next(), parts.next()) {
+                (Some(lhs), Some(rhs), None) => Ok(Round {
+                    opponent: Hand::from(lhs).map_err(|e| e.locate(data, row))?,
+                    response: Hand::from(rhs).map_err(|e| e.locate(data, row))?,
+                    outcome: Outcome::from(rhs).map_err(|e| e.locate(data, row))?,
+                }),
+                _ => Err(ParseError::at(
+                    data,
+                    row,
+                    "expected \"<opponent> <response>\"",
+                )),
+            }
+        })
+        .collect()
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input<'a> = Vec<Round>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        parse_rounds(input)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        let mut score: u32 = 0;
+
+        for round in input {
+            let me = &round.response;
+            let outcome = me.play(round.opponent.clone());
+
+            score += outcome.points() + me.points();
+        }
+
+        Ok(score.into())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        let mut score: u32 = 0;
+
+        for round in input {
+            let outcome = &round.outcome;
+            let me = outcome.infer_hand(round.opponent.clone());
+
+            score += outcome.points() + me.points();
+        }
+
+        Ok(score.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::examples!(Day2, "day-2", {
+        example: { part_1: 15, part_2: 12 },
+        draws: { part_1: 15, part_2: 15 },
+    });
+}
@@ -0,0 +1,126 @@
+use aoc_common::{parse, Answer, ParseError, Solution, SolveError};
+use std::collections::HashMap;
+
+fn get_priority_map() -> HashMap<char, u32> {
+    let mut map = HashMap::with_capacity(52);
+    let mut count = 1;
+
+    for c in 'a'..='z' {
+        map.insert(c, count);
+        count += 1;
+    }
+
+    for c in 'A'..='Z' {
+        map.insert(c, count);
+        count += 1;
+    }
+
+    map
+}
+
+fn find_shared_item_type(comp1: &str, comp2: &str) -> Option<char> {
+    comp1.chars().find(|&c| comp2.contains(c))
+}
+
+fn find_group_shared_item_type(comps: &[&str]) -> Option<char> {
+    comps[0]
+        .chars()
+        .find(|&c| comps[1].contains(c) && comps[2].contains(c))
+}
+
+fn get_priority(
+    priorities: &HashMap<char, u32>,
+    rucksack: &str,
+    item: Option<char>,
+) -> Result<u32, SolveError> {
+    let item =
+        item.ok_or_else(|| SolveError::new(format!("no common item type in {:?}", rucksack)))?;
+
+    priorities
+        .get(&item)
+        .copied()
+        .ok_or_else(|| SolveError::new(format!("no priority for item type {:?}", item)))
+}
+
+fn parse_rucksacks(data: &str) -> Result<Vec<&str>, ParseError> {
+    parse::lines(data)
+        .map(|rucksack| {
+            let rucksack = rucksack.trim();
+
+            if let Some(index) = rucksack.find(|c: char| !c.is_ascii_alphabetic()) {
+                return Err(ParseError::at(
+                    data,
+                    &rucksack[index..index + 1],
+                    "expected an item type of a-z or A-Z",
+                ));
+            }
+
+            if rucksack.len() % 2 != 0 {
+                return Err(ParseError::at(
+                    data,
+                    rucksack,
+                    "expected two equally sized compartments",
+                ));
+            }
+
+            Ok(rucksack)
+        })
+        .collect()
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input<'a> = Vec<&'a str>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        parse_rucksacks(input)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        let priorities = get_priority_map();
+
+        let sum = input
+            .iter()
+            .map(|rucksack| {
+                let half = rucksack.len() / 2;
+                let comp1 = &rucksack[0..half];
+                let comp2 = &rucksack[half..];
+
+                get_priority(&priorities, rucksack, find_shared_item_type(comp1, comp2))
+            })
+            .sum::<Result<u32, _>>()?;
+
+        Ok(sum.into())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        let priorities = get_priority_map();
+
+        let sum = input
+            .chunks(3)
+            .map(|comps| {
+                if comps.len() != 3 {
+                    return Err(SolveError::new(format!(
+                        "expected groups of 3 rucksacks, found {} from {:?}",
+                        comps.len(),
+                        comps[0]
+                    )));
+                }
+
+                get_priority(&priorities, comps[0], find_group_shared_item_type(comps))
+            })
+            .sum::<Result<u32, _>>()?;
+
+        Ok(sum.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::examples!(Day3, "day-3", {
+        example: { part_1: 157, part_2: 70 },
+    });
+}
@@ -0,0 +1,68 @@
+use aoc_common::{parse, Answer, ParseError, Solution, SolveError};
+
+pub type Assignment = (u8, u8);
+
+fn get_assignment_pair(line: &str) -> Result<(Assignment, Assignment), ParseError> {
+    match parse::integers::<u8>(line)?[..] {
+        [a1, a2, b1, b2] => Ok(((a1, a2), (b1, b2))),
+        _ => Err(ParseError::new("expected \"A-B,C-D\"", line)),
+    }
+}
+
+fn parse_assignments(data: &str) -> Result<Vec<(Assignment, Assignment)>, ParseError> {
+    parse::lines(data)
+        .map(|line| get_assignment_pair(line).map_err(|e| e.locate(data, line)))
+        .collect()
+}
+
+fn in_range(value: u8, range: &Assignment) -> bool {
+    value >= range.0 && value <= range.1
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input<'a> = Vec<(Assignment, Assignment)>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        parse_assignments(input)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        let total = input
+            .iter()
+            .filter(|assignments| {
+                let a = assignments.0;
+                let b = assignments.1;
+
+                a.0 <= b.0 && a.1 >= b.1 || b.0 <= a.0 && b.1 >= a.1
+            })
+            .count();
+
+        Ok(total.into())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        let total = input
+            .iter()
+            .filter(|assignments| {
+                let a = assignments.0;
+                let b = assignments.1;
+
+                in_range(b.0, &a) || in_range(b.1, &a) || in_range(a.0, &b) || in_range(a.1, &b)
+            })
+            .count();
+
+        Ok(total.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::examples!(Day4, "day-4", {
+        example: { part_1: 2, part_2: 4 },
+        touching: { part_1: 1, part_2: 2 },
+    });
+}
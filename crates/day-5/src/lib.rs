@@ -0,0 +1,227 @@
+use aoc_common::{parse, Answer, ParseError, Solution, SolveError};
+use std::{
+    collections::{BTreeMap, VecDeque},
+    str::FromStr,
+};
+
+pub type Cargo<'l> = BTreeMap<u8, VecDeque<&'l str>>;
+
+#[derive(Debug)]
+pub struct Move {
+    count: u8,
+    from: u8,
+    to: u8,
+}
+
+impl FromStr for Move {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let number = |value: &str| {
+            value
+                .parse::<u8>()
+                .map_err(|_| ParseError::new("expected a number", value))
+        };
+
+        match s.split_whitespace().collect::<Vec<_>>()[..] {
+            ["move", count, "from", from, "to", to] => Ok(Move {
+                count: number(count)?,
+                from: number(from)?,
+                to: number(to)?,
+            }),
+            _ => Err(ParseError::new("expected \"move N from A to B\"", s)),
+        }
+    }
+}
+
+fn parse_input(data: &str) -> Result<(Cargo<'_>, Vec<Move>), ParseError> {
+    let mut cargo: Cargo = BTreeMap::new();
+    let mut sections = parse::blocks(data);
+    let drawing = sections.next().unwrap_or_default();
+
+    let mut rows = drawing.lines().collect::<Vec<_>>();
+    let labels = rows
+        .pop()
+        .filter(|row| row.trim_start().starts_with(|c: char| c.is_ascii_digit()))
+        .ok_or_else(|| ParseError::at(data, drawing, "expected a row of stack numbers"))?;
+
+    // Every labelled stack exists, even when it starts empty
+    for label in labels.split_whitespace() {
+        let stack = label
+            .parse()
+            .map_err(|_| ParseError::at(data, label, "expected a stack number"))?;
+
+        cargo.insert(stack, VecDeque::new());
+    }
+
+    // Parse cargo (janky but works)
+    for line in rows {
+        let mut curr_col = 0;
+        let mut cargo_col = 1;
+
+        while curr_col < line.len() {
+            // Remove brackets
+            let item = line
+                .get((curr_col + 1)..(curr_col + 2))
+                .ok_or_else(|| ParseError::at(data, line, "expected crates like \"[A]\""))?;
+
+            if !item.trim().is_empty() {
+                cargo
+                    .get_mut(&cargo_col)
+                    .ok_or_else(|| {
+                        ParseError::at(data, item, "expected a crate on a numbered stack")
+                    })?
+                    .push_front(item);
+            }
+
+            curr_col += 4;
+            cargo_col += 1;
+        }
+    }
+
+    let mut moves = vec![];
+
+    for line in sections.flat_map(parse::lines) {
+        let action = line.parse::<Move>().map_err(|e| e.locate(data, line))?;
+
+        for stack in [action.from, action.to] {
+            if !cargo.contains_key(&stack) {
+                return Err(ParseError::at(
+                    data,
+                    line,
+                    format!("unknown stack {}", stack),
+                ));
+            }
+        }
+
+        moves.push(action);
+    }
+
+    Ok((cargo, moves))
+}
+
+fn take_crates<'l>(cargo: &mut Cargo<'l>, action: &Move) -> Result<VecDeque<&'l str>, SolveError> {
+    let from = cargo.get_mut(&action.from).expect("Missing from!");
+
+    // Extract tail up to count
+    let at = from
+        .len()
+        .checked_sub(action.count as usize)
+        .ok_or_else(|| {
+            SolveError::new(format!(
+                "cannot move {} crates from stack {}",
+                action.count, action.from
+            ))
+        })?;
+
+    Ok(from.split_off(at))
+}
+
+fn get_result(cargo: &Cargo) -> String {
+    cargo
+        .values()
+        .filter_map(|stack| stack.back().copied())
+        .collect::<Vec<_>>()
+        .join("")
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input<'a> = (Cargo<'a>, Vec<Move>);
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        let (cargo, moves) = input;
+        let mut cargo = cargo.clone();
+
+        for action in moves {
+            let mut buffer = take_crates(&mut cargo, action)?;
+
+            {
+                let to = cargo.get_mut(&action.to).expect("Missing to!");
+
+                // Push in reverse
+                while !buffer.is_empty() {
+                    to.push_back(buffer.remove(buffer.len() - 1).unwrap());
+                }
+            }
+        }
+
+        Ok(get_result(&cargo).into())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        let (cargo, moves) = input;
+        let mut cargo = cargo.clone();
+
+        for action in moves {
+            let mut buffer = take_crates(&mut cargo, action)?;
+
+            {
+                let to = cargo.get_mut(&action.to).expect("Missing to!");
+                to.append(&mut buffer);
+            }
+        }
+
+        Ok(get_result(&cargo).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::examples!(Day5, "day-5", {
+        example: { part_1: "CMZ", part_2: "MCD" },
+    });
+
+    #[test]
+    fn invalid_move() {
+        let data = "[A] [B]\n 1   2\n\nmove 1 from 1 to 2\nmvoe 1 from 2 to 1\n";
+
+        assert_eq!(
+            parse_input(data).unwrap_err().to_string(),
+            "line 5: expected \"move N from A to B\", found \"mvoe 1 from 2 to 1\""
+        );
+    }
+
+    #[test]
+    fn empty_stack() {
+        let data = "    [B]\n 1   2\n\nmove 1 from 2 to 1\n";
+        let (cargo, moves) = parse_input(data).unwrap();
+
+        assert_eq!(cargo[&1], VecDeque::<&str>::new());
+        assert_eq!(cargo[&2], VecDeque::from(["B"]));
+        assert_eq!(Day5.part_1(&(cargo, moves)), Ok(Answer::from("B")));
+    }
+
+    #[test]
+    fn unlabelled_stack() {
+        assert_eq!(
+            parse_input("[A] [B]\n 1\n\nmove 1 from 1 to 1\n")
+                .unwrap_err()
+                .to_string(),
+            "line 1, column 6: expected a crate on a numbered stack, found \"B\""
+        );
+        assert_eq!(
+            parse_input("[A]\n\nmove 1 from 1 to 1\n")
+                .unwrap_err()
+                .to_string(),
+            "line 1: expected a row of stack numbers, found \"[A]\""
+        );
+    }
+
+    #[test]
+    fn unknown_stack() {
+        let data = "[A] [B]\n 1   2\n\nmove 1 from 3 to 2\n";
+
+        assert_eq!(
+            parse_input(data).unwrap_err().to_string(),
+            "line 4: unknown stack 3, found \"move 1 from 3 to 2\""
+        );
+    }
+}
@@ -0,0 +1,71 @@
+use aoc_common::{Answer, ParseError, Solution, SolveError};
+
+fn is_all_diff_chars_u8(marker: &[u8]) -> bool {
+    let mut o = 0;
+    let mut i;
+
+    while o < marker.len() {
+        i = 0;
+
+        while i < marker.len() {
+            if o != i && marker[o] == marker[i] {
+                return false;
+            }
+
+            i += 1;
+        }
+
+        o += 1;
+    }
+
+    true
+}
+
+fn find_marker(data: &[u8], size: usize) -> Result<usize, SolveError> {
+    data.windows(size)
+        .position(is_all_diff_chars_u8)
+        .map(|index| index + size)
+        .ok_or_else(|| SolveError::new(format!("no marker of {} different characters found", size)))
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input<'a> = &'a [u8];
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        Ok(input.trim().as_bytes())
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(find_marker(input, 4)?.into())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(find_marker(input, 14)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::examples!(Day6, "day-6", {
+        example_1: { part_1: 7, part_2: 19 },
+        example_2: { part_1: 5, part_2: 23 },
+        example_3: { part_1: 6, part_2: 23 },
+        example_4: { part_1: 10, part_2: 29 },
+        example_5: { part_1: 11, part_2: 26 },
+    });
+
+    #[test]
+    fn test_diff() {
+        assert!(is_all_diff_chars_u8(b"abcd"));
+        assert!(is_all_diff_chars_u8(b"wxyz"));
+        assert!(is_all_diff_chars_u8(b"fourte3ncha4s!"));
+
+        assert!(!is_all_diff_chars_u8(b"abcc"));
+        assert!(!is_all_diff_chars_u8(b"abbc"));
+        assert!(!is_all_diff_chars_u8(b"aaaa"));
+    }
+}
@@ -0,0 +1,64 @@
+use aoc_7::{du, format_du, interpret, tree, DuOptions};
+use aoc_common::cli::{self, Args};
+
+const USAGE: &str = "Usage:
+  disk-report tree <FILE|->
+  disk-report du [--sort <size|name>] [--min <SIZE>] [--max <SIZE>] <FILE|->";
+
+enum Report {
+    Tree,
+    Du(DuOptions),
+}
+
+struct Options {
+    report: Report,
+    input: String,
+}
+
+fn parse_args<I: Iterator<Item = String>>(mut args: I) -> Result<Options, String> {
+    let mut report = match args.next().as_deref() {
+        Some("tree") => Report::Tree,
+        Some("du") => Report::Du(DuOptions::default()),
+        Some(command) => return Err(format!("Unknown command {}", command)),
+        None => return Err(String::from("Missing command")),
+    };
+    let mut args = Args::new(args);
+
+    while let Some(flag) = args.next_flag()? {
+        match (&mut report, flag.as_str()) {
+            (Report::Du(options), "--sort" | "-s") => {
+                options.sort = args.value(&flag)?.parse()?;
+            }
+            (Report::Du(options), "--min") => {
+                options.min_size = Some(args.parse(&flag)?);
+            }
+            (Report::Du(options), "--max") => {
+                options.max_size = Some(args.parse(&flag)?);
+            }
+            (_, unknown) => {
+                return Err(cli::unknown_argument(unknown));
+            }
+        }
+    }
+
+    Ok(Options {
+        report,
+        input: args.input()?,
+    })
+}
+
+fn main() {
+    let options = parse_args(std::env::args().skip(1))
+        .unwrap_or_else(|error| cli::fail_with_usage(error, USAGE));
+    let input = cli::read_input(&options.input).unwrap_or_else(|error| cli::fail(error));
+    let fs = interpret(&input).unwrap_or_else(|error| cli::fail(error));
+
+    let lines = match &options.report {
+        Report::Tree => tree(&fs),
+        Report::Du(du_options) => format_du(&du(&fs, du_options)),
+    };
+
+    for line in lines {
+        println!("{}", line);
+    }
+}
@@ -0,0 +1,224 @@
+use std::collections::BTreeMap;
+
+/// The index of a directory within a `FileSystem`.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct DirId(usize);
+
+#[derive(Debug)]
+pub struct Dir<'t> {
+    pub name: &'t str,
+    pub parent: Option<DirId>,
+    dirs: BTreeMap<&'t str, DirId>,
+    files: BTreeMap<&'t str, u64>,
+    /// Cumulative size of the files within, including nested directories.
+    size: u64,
+}
+
+impl<'t> Dir<'t> {
+    fn new(name: &'t str, parent: Option<DirId>) -> Self {
+        Dir {
+            name,
+            parent,
+            dirs: BTreeMap::new(),
+            files: BTreeMap::new(),
+            size: 0,
+        }
+    }
+
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// Nested directories, ordered by name.
+    pub fn dirs(&self) -> impl Iterator<Item = (&'t str, DirId)> + '_ {
+        self.dirs.iter().map(|(name, id)| (*name, *id))
+    }
+
+    /// Files and their sizes, ordered by name.
+    pub fn files(&self) -> impl Iterator<Item = (&'t str, u64)> + '_ {
+        self.files.iter().map(|(name, size)| (*name, *size))
+    }
+}
+
+/// Directories stored in an arena and linked by ID, with the root first.
+/// Sizes are computed once when the tree is complete, so every query is
+/// a lookup rather than a walk of the subtree.
+#[derive(Debug)]
+pub struct FileSystem<'t> {
+    dirs: Vec<Dir<'t>>,
+}
+
+impl<'t> Default for FileSystem<'t> {
+    fn default() -> Self {
+        FileSystem {
+            dirs: vec![Dir::new("/", None)],
+        }
+    }
+}
+
+impl<'t> FileSystem<'t> {
+    pub const ROOT: DirId = DirId(0);
+
+    pub fn dir(&self, id: DirId) -> &Dir<'t> {
+        &self.dirs[id.0]
+    }
+
+    pub fn size(&self, id: DirId) -> u64 {
+        self.dir(id).size
+    }
+
+    pub fn parent(&self, id: DirId) -> Option<DirId> {
+        self.dir(id).parent
+    }
+
+    pub fn child(&self, id: DirId, name: &str) -> Option<DirId> {
+        self.dir(id).dirs.get(name).copied()
+    }
+
+    /// The absolute path of a directory, such as `/a/e`.
+    pub fn path(&self, id: DirId) -> String {
+        let mut names = vec![];
+        let mut current = id;
+
+        while let Some(parent) = self.parent(current) {
+            names.push(self.dir(current).name);
+            current = parent;
+        }
+
+        names.reverse();
+
+        format!("/{}", names.join("/"))
+    }
+
+    /// Follows `path` from `from`, where an absolute path starts at the root.
+    /// Returns `None` if a directory doesn't exist or `..` leaves the root.
+    pub fn resolve(&self, from: DirId, path: &str) -> Option<DirId> {
+        let mut current = if path.starts_with('/') {
+            Self::ROOT
+        } else {
+            from
+        };
+
+        for segment in path.split('/') {
+            current = match segment {
+                "" | "." => current,
+                ".." => self.parent(current)?,
+                name => self.child(current, name)?,
+            };
+        }
+
+        Some(current)
+    }
+
+    /// Follows `path` like `resolve`, creating any directories that don't exist.
+    pub(crate) fn resolve_or_create(&mut self, from: DirId, path: &'t str) -> Option<DirId> {
+        let mut current = if path.starts_with('/') {
+            Self::ROOT
+        } else {
+            from
+        };
+
+        for segment in path.split('/') {
+            current = match segment {
+                "" | "." => current,
+                ".." => self.parent(current)?,
+                name => self.add_dir(current, name),
+            };
+        }
+
+        Some(current)
+    }
+
+    /// Every directory, parents before their children.
+    pub fn dirs(&self) -> impl Iterator<Item = (DirId, &Dir<'t>)> {
+        self.dirs
+            .iter()
+            .enumerate()
+            .map(|(index, dir)| (DirId(index), dir))
+    }
+
+    /// Returns the nested directory called `name`, creating it if needed.
+    pub(crate) fn add_dir(&mut self, parent: DirId, name: &'t str) -> DirId {
+        if let Some(id) = self.child(parent, name) {
+            return id;
+        }
+
+        let id = DirId(self.dirs.len());
+
+        self.dirs.push(Dir::new(name, Some(parent)));
+        self.dirs[parent.0].dirs.insert(name, id);
+
+        id
+    }
+
+    pub(crate) fn add_file(&mut self, parent: DirId, name: &'t str, size: u64) {
+        self.dirs[parent.0].files.insert(name, size);
+    }
+
+    /// Computes the cumulative size of every directory. Directories are only
+    /// created within existing ones, so children always follow their parent
+    /// and a single reverse pass visits each child before its parent.
+    pub(crate) fn compute_sizes(&mut self) {
+        for dir in &mut self.dirs {
+            dir.size = dir.files.values().sum();
+        }
+
+        for index in (0..self.dirs.len()).rev() {
+            if let Some(parent) = self.dirs[index].parent {
+                self.dirs[parent.0].size += self.dirs[index].size;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn links_and_sizes_dirs() {
+        let mut fs = FileSystem::default();
+        let a = fs.add_dir(FileSystem::ROOT, "a");
+        let e = fs.add_dir(a, "e");
+
+        fs.add_file(FileSystem::ROOT, "b.txt", 100);
+        fs.add_file(a, "f", 20);
+        fs.add_file(e, "i", 3);
+
+        assert_eq!(fs.add_dir(FileSystem::ROOT, "a"), a);
+
+        fs.compute_sizes();
+
+        assert_eq!(fs.size(e), 3);
+        assert_eq!(fs.size(a), 23);
+        assert_eq!(fs.size(FileSystem::ROOT), 123);
+        assert_eq!(fs.parent(e), Some(a));
+        assert_eq!(fs.parent(FileSystem::ROOT), None);
+        assert_eq!(fs.child(a, "e"), Some(e));
+        assert_eq!(
+            fs.dirs().map(|(_, dir)| dir.name).collect::<Vec<_>>(),
+            ["/", "a", "e"]
+        );
+    }
+
+    #[test]
+    fn resolves_paths() {
+        let mut fs = FileSystem::default();
+        let e = fs.resolve_or_create(FileSystem::ROOT, "a/e").unwrap();
+        let a = fs.parent(e).unwrap();
+
+        assert_eq!(fs.path(e), "/a/e");
+        assert_eq!(fs.path(FileSystem::ROOT), "/");
+        assert_eq!(fs.resolve(e, ".."), Some(a));
+        assert_eq!(fs.resolve(e, "/a/./e"), Some(e));
+        assert_eq!(fs.resolve(e, "../../a//e/"), Some(e));
+        assert_eq!(fs.resolve(e, "/"), Some(FileSystem::ROOT));
+        assert_eq!(fs.resolve(a, "d"), None);
+        assert_eq!(fs.resolve(a, "../.."), None);
+
+        let d = fs.resolve_or_create(e, "/d").unwrap();
+
+        assert_eq!(fs.child(FileSystem::ROOT, "d"), Some(d));
+        assert_eq!(fs.resolve_or_create(d, "../.."), None);
+    }
+}
@@ -0,0 +1,64 @@
+use aoc_common::{Answer, ParseError, Solution, SolveError};
+
+mod filesystem;
+mod report;
+mod transcript;
+
+pub use filesystem::{Dir, DirId, FileSystem};
+pub use report::{du, format_du, tree, DuOptions, SortBy};
+pub use transcript::interpret;
+
+/// Total size of the directories under 100,000, counting nested
+/// directories again within each of their parents.
+fn sum_tree(fs: &FileSystem) -> u64 {
+    fs.dirs()
+        .map(|(_, dir)| dir.size())
+        .filter(|size| *size < 100_000)
+        .sum()
+}
+
+/// Size of the smallest directory of at least `min_size`.
+fn sum_tree_closest(fs: &FileSystem, min_size: u64) -> Option<u64> {
+    fs.dirs()
+        .map(|(_, dir)| dir.size())
+        .filter(|size| *size >= min_size)
+        .min()
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input<'a> = FileSystem<'a>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        interpret(input)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(sum_tree(input).into())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        let total_space: u64 = 70_000_000;
+        let used_space = input.size(FileSystem::ROOT);
+        let free_space = total_space.checked_sub(used_space).ok_or_else(|| {
+            SolveError::new(format!("used space of {} exceeds the disk", used_space))
+        })?;
+        let find_space = 30_000_000_u64.saturating_sub(free_space);
+
+        sum_tree_closest(input, find_space)
+            .map(Answer::from)
+            .ok_or_else(|| SolveError::new("no directory frees up enough space"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::examples!(Day7, "day-7", {
+        example: { part_1: 95437, part_2: 24933642 },
+        repeated_names: { part_1: 1400 },
+        paths: { part_1: 95437, part_2: 24933642 },
+    });
+}
@@ -0,0 +1,171 @@
+use crate::{DirId, FileSystem};
+use std::str::FromStr;
+
+/// Renders the filesystem in the puzzle's indented format, such as
+/// `- a (dir)` and `- b.txt (file, size=14848514)`, with the entries of
+/// each directory ordered by name.
+pub fn tree(fs: &FileSystem) -> Vec<String> {
+    let mut lines = vec![String::from("- / (dir)")];
+
+    tree_entries(fs, FileSystem::ROOT, 1, &mut lines);
+
+    lines
+}
+
+fn tree_entries(fs: &FileSystem, id: DirId, depth: usize, lines: &mut Vec<String>) {
+    let dir = fs.dir(id);
+    let indent = "  ".repeat(depth);
+    let mut dirs = dir.dirs().peekable();
+    let mut files = dir.files().peekable();
+
+    loop {
+        let next_dir = match (dirs.peek(), files.peek()) {
+            (Some((dir_name, _)), Some((file_name, _))) => dir_name <= file_name,
+            (Some(_), None) => true,
+            (None, Some(_)) => false,
+            (None, None) => break,
+        };
+
+        if next_dir {
+            if let Some((name, child)) = dirs.next() {
+                lines.push(format!("{}- {} (dir)", indent, name));
+                tree_entries(fs, child, depth + 1, lines);
+            }
+        } else if let Some((name, size)) = files.next() {
+            lines.push(format!("{}- {} (file, size={})", indent, name, size));
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum SortBy {
+    /// Largest first, then by path.
+    #[default]
+    Size,
+    Name,
+}
+
+impl FromStr for SortBy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, String> {
+        match s {
+            "size" => Ok(SortBy::Size),
+            "name" | "path" => Ok(SortBy::Name),
+            _ => Err(format!("Unknown sort {}, expected size or name", s)),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DuOptions {
+    pub sort: SortBy,
+    /// Only include directories of at least this size.
+    pub min_size: Option<u64>,
+    /// Only include directories of at most this size.
+    pub max_size: Option<u64>,
+}
+
+/// The path and cumulative size of every directory within the size range.
+pub fn du(fs: &FileSystem, options: &DuOptions) -> Vec<(String, u64)> {
+    let mut entries = fs
+        .dirs()
+        .map(|(id, dir)| (id, dir.size()))
+        .filter(|(_, size)| options.min_size.is_none_or(|min| *size >= min))
+        .filter(|(_, size)| options.max_size.is_none_or(|max| *size <= max))
+        .map(|(id, size)| (fs.path(id), size))
+        .collect::<Vec<_>>();
+
+    match options.sort {
+        SortBy::Size => entries.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0))),
+        SortBy::Name => entries.sort(),
+    }
+
+    entries
+}
+
+/// Formats `du` entries as a table of right aligned sizes and paths.
+pub fn format_du(entries: &[(String, u64)]) -> Vec<String> {
+    let width = entries
+        .iter()
+        .map(|(_, size)| size.to_string().len())
+        .max()
+        .unwrap_or(0);
+
+    entries
+        .iter()
+        .map(|(path, size)| format!("{:>width$}  {}", size, path, width = width))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interpret;
+
+    const EXAMPLE: &str = include_str!("../../../examples/day-7/example.txt");
+
+    #[test]
+    fn renders_tree() {
+        let fs = interpret(EXAMPLE).unwrap();
+
+        assert_eq!(
+            tree(&fs),
+            [
+                "- / (dir)",
+                "  - a (dir)",
+                "    - e (dir)",
+                "      - i (file, size=584)",
+                "    - f (file, size=29116)",
+                "    - g (file, size=2557)",
+                "    - h.lst (file, size=62596)",
+                "  - b.txt (file, size=14848514)",
+                "  - c.dat (file, size=8504156)",
+                "  - d (dir)",
+                "    - d.ext (file, size=5626152)",
+                "    - d.log (file, size=8033020)",
+                "    - j (file, size=4060174)",
+                "    - k (file, size=7214296)",
+            ]
+        );
+    }
+
+    #[test]
+    fn lists_dirs() {
+        let fs = interpret(EXAMPLE).unwrap();
+
+        assert_eq!(
+            format_du(&du(&fs, &DuOptions::default())),
+            [
+                "48381165  /",
+                "24933642  /d",
+                "   94853  /a",
+                "     584  /a/e",
+            ]
+        );
+        assert_eq!(
+            du(
+                &fs,
+                &DuOptions {
+                    sort: SortBy::Name,
+                    max_size: Some(100_000),
+                    ..DuOptions::default()
+                }
+            ),
+            [(String::from("/a"), 94853), (String::from("/a/e"), 584)]
+        );
+        assert_eq!(
+            du(
+                &fs,
+                &DuOptions {
+                    min_size: Some(8_381_165),
+                    ..DuOptions::default()
+                }
+            ),
+            [
+                (String::from("/"), 48381165),
+                (String::from("/d"), 24933642)
+            ]
+        );
+    }
+}
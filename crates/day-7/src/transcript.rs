@@ -0,0 +1,146 @@
+use crate::{DirId, FileSystem};
+use aoc_common::ParseError;
+
+/// Replays a terminal transcript of `cd` and `ls` commands, rebuilding the
+/// filesystem they explored. Directories are created when first seen, either
+/// listed or visited, and listing a directory again replaces its entries by
+/// name rather than adding to them.
+pub fn interpret(data: &str) -> Result<FileSystem<'_>, ParseError> {
+    let mut fs = FileSystem::default();
+    let mut current_dir = FileSystem::ROOT;
+    let mut is_listing = false;
+
+    for line in data.lines() {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        if let Some(command) = line.strip_prefix('$') {
+            let mut args = command.split_whitespace();
+
+            is_listing = false;
+
+            match args.next() {
+                Some("cd") => {
+                    let path = args.next().ok_or_else(|| {
+                        ParseError::at(data, line, "expected a directory to cd into")
+                    })?;
+
+                    current_dir = fs.resolve_or_create(current_dir, path).ok_or_else(|| {
+                        ParseError::new("path leaves the root directory", path).locate(data, line)
+                    })?;
+                }
+                Some("ls") => {
+                    is_listing = true;
+                }
+                Some(name) => {
+                    return Err(ParseError::new("unknown command", name).locate(data, line));
+                }
+                None => {
+                    return Err(ParseError::at(data, line, "expected a command"));
+                }
+            }
+        } else if is_listing {
+            list_entry(&mut fs, current_dir, data, line)?;
+        } else {
+            return Err(ParseError::at(
+                data,
+                line,
+                "expected a command, as only \"ls\" produces output",
+            ));
+        }
+    }
+
+    fs.compute_sizes();
+
+    Ok(fs)
+}
+
+fn list_entry<'t>(
+    fs: &mut FileSystem<'t>,
+    dir: DirId,
+    data: &'t str,
+    line: &'t str,
+) -> Result<(), ParseError> {
+    let mut parts = line.split_whitespace();
+    let (Some(meta), Some(name)) = (parts.next(), parts.next()) else {
+        return Err(ParseError::at(
+            data,
+            line,
+            "expected \"dir <name>\" or \"<size> <name>\"",
+        ));
+    };
+
+    if meta == "dir" {
+        fs.add_dir(dir, name);
+    } else {
+        fs.add_file(
+            dir,
+            name,
+            meta.parse()
+                .map_err(|_| ParseError::new("expected a file size", meta).locate(data, line))?,
+        );
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message(data: &str) -> String {
+        interpret(data).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn creates_dirs_on_demand() {
+        let fs = interpret("$ cd /a/b\n$ ls\n10 x\n$ cd ../c\n$ ls\n20 y\n").unwrap();
+        let a = fs.resolve(FileSystem::ROOT, "a").unwrap();
+
+        assert_eq!(fs.size(FileSystem::ROOT), 30);
+        assert_eq!(
+            fs.dir(a).dirs().map(|(name, _)| name).collect::<Vec<_>>(),
+            ["b", "c"]
+        );
+        assert_eq!(
+            fs.resolve(FileSystem::ROOT, "/a/c").map(|c| fs.size(c)),
+            Some(20)
+        );
+    }
+
+    #[test]
+    fn deduplicates_listings() {
+        let fs = interpret(
+            "$ ls\n10 x\ndir a\n$ ls\n10 x\ndir a\n15 y\n$ cd a\n$ ls\n5 z\n$ cd /\n$ ls\n10 x\n",
+        )
+        .unwrap();
+
+        assert_eq!(fs.size(FileSystem::ROOT), 30);
+        assert_eq!(fs.dirs().count(), 2);
+    }
+
+    #[test]
+    fn reports_errors() {
+        assert_eq!(
+            message("$ cd /\n$ ls\n10 x\n$ pwd\n"),
+            "line 4, column 3: unknown command, found \"pwd\""
+        );
+        assert_eq!(
+            message("$ cd /\n$ cd a/../..\n"),
+            "line 2, column 6: path leaves the root directory, found \"a/../..\""
+        );
+        assert_eq!(
+            message("$ cd /\n10 x\n"),
+            "line 2: expected a command, as only \"ls\" produces output, found \"10 x\""
+        );
+        assert_eq!(
+            message("$ ls\nten x\n"),
+            "line 2: expected a file size, found \"ten\""
+        );
+        assert_eq!(
+            message("$ cd\n"),
+            "line 1: expected a directory to cd into, found \"$ cd\""
+        );
+    }
+}
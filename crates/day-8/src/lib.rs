@@ -0,0 +1,139 @@
+use aoc_common::{Answer, Direction, Grid, ParseError, Point, Solution, SolveError};
+
+#[derive(Debug)]
+pub struct Forest {
+    trees: Grid<u8>,
+}
+
+impl Forest {
+    pub fn new(s: &str) -> Result<Self, ParseError> {
+        let trees = Grid::try_parse(s, |c| c.to_digit(10).map(|height| height as u8))?;
+
+        if trees.width() == 0 || trees.height() == 0 {
+            return Err(ParseError::new("expected a grid of tree heights", ""));
+        }
+
+        Ok(Forest { trees })
+    }
+
+    pub fn find_scenic_score(&self) -> usize {
+        self.trees
+            .points()
+            .map(|point| self.calc_score(point.x as usize, point.y as usize))
+            .max()
+            .unwrap_or(0)
+    }
+
+    pub fn find_visible_trees(&self) -> usize {
+        self.trees
+            .points()
+            .filter(|point| self.is_visible(point.x as usize, point.y as usize))
+            .count()
+    }
+
+    /// Product of the viewing distances in each direction, where each
+    /// distance stops at (and includes) the first tree at least as tall.
+    pub fn calc_score(&self, x: usize, y: usize) -> usize {
+        let point = Point::new(x as i32, y as i32);
+        let height = self.trees[point];
+
+        Direction::ALL
+            .iter()
+            .map(|direction| {
+                self.trees
+                    .cast(point, *direction, |tree| *tree >= height)
+                    .distance()
+            })
+            .product()
+    }
+
+    /// Whether the tree can be seen from outside the forest in any direction.
+    pub fn is_visible(&self, x: usize, y: usize) -> bool {
+        let point = Point::new(x as i32, y as i32);
+        let height = self.trees[point];
+
+        Direction::ALL.iter().any(|direction| {
+            !self
+                .trees
+                .cast(point, *direction, |tree| *tree >= height)
+                .is_hit()
+        })
+    }
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input<'a> = Forest;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        Forest::new(input)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(input.find_visible_trees().into())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(input.find_scenic_score().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::examples!(Day8, "day-8", {
+        example: { part_1: 21, part_2: 8 },
+    });
+
+    #[test]
+    fn visible() {
+        let forest = Forest::new("30373\n25512\n65332\n33549\n35390").unwrap();
+
+        // Edges
+        assert!(forest.is_visible(0, 0));
+        assert!(forest.is_visible(1, 0));
+        assert!(forest.is_visible(2, 0));
+        assert!(forest.is_visible(3, 0));
+        assert!(forest.is_visible(4, 0));
+
+        assert!(forest.is_visible(0, 1));
+        assert!(forest.is_visible(4, 1));
+
+        assert!(forest.is_visible(0, 2));
+        assert!(forest.is_visible(4, 2));
+
+        assert!(forest.is_visible(0, 3));
+        assert!(forest.is_visible(4, 3));
+
+        assert!(forest.is_visible(0, 4));
+        assert!(forest.is_visible(1, 4));
+        assert!(forest.is_visible(2, 4));
+        assert!(forest.is_visible(3, 4));
+        assert!(forest.is_visible(4, 4));
+
+        // Inner
+        assert!(forest.is_visible(1, 1));
+        assert!(forest.is_visible(2, 1));
+        assert!(!forest.is_visible(3, 1));
+
+        assert!(forest.is_visible(1, 2));
+        assert!(!forest.is_visible(2, 2));
+        assert!(forest.is_visible(3, 2));
+
+        assert!(!forest.is_visible(1, 3));
+        assert!(forest.is_visible(2, 3));
+        assert!(!forest.is_visible(3, 3));
+    }
+
+    #[test]
+    fn score() {
+        let forest = Forest::new("30373\n25512\n65332\n33549\n35390").unwrap();
+
+        assert_eq!(forest.calc_score(2, 1), 4);
+        assert_eq!(forest.calc_score(2, 3), 8);
+        assert_eq!(forest.calc_score(0, 2), 0);
+        assert_eq!(forest.find_scenic_score(), 8);
+    }
+}
@@ -0,0 +1,79 @@
+use aoc_9::{Bridge, Day9};
+use aoc_common::{
+    cli::{self, Args},
+    Solution,
+};
+use std::{path::PathBuf, process};
+
+const USAGE: &str = "Usage:
+  render-rope [--knots <N>] [--steps] [--frames <DIR>] <FILE|->";
+
+struct Options {
+    knots: usize,
+    /// Print every step instead of only the final state.
+    steps: bool,
+    frames: Option<PathBuf>,
+    input: String,
+}
+
+fn parse_args<I: Iterator<Item = String>>(args: I) -> Result<Options, String> {
+    let mut args = Args::new(args);
+    let mut knots = 10;
+    let mut steps = false;
+    let mut frames = None;
+
+    while let Some(flag) = args.next_flag()? {
+        match flag.as_str() {
+            "--knots" | "-k" => {
+                knots = args.parse_where(&flag, |knots| *knots >= 2)?;
+            }
+            "--steps" => {
+                steps = true;
+            }
+            "--frames" => {
+                frames = Some(PathBuf::from(args.value(&flag)?));
+            }
+            unknown => {
+                return Err(cli::unknown_argument(unknown));
+            }
+        }
+    }
+
+    Ok(Options {
+        knots,
+        steps,
+        frames,
+        input: args.input()?,
+    })
+}
+
+fn main() {
+    let options = parse_args(std::env::args().skip(1))
+        .unwrap_or_else(|error| cli::fail_with_usage(error, USAGE));
+    let input = cli::read_input(&options.input).unwrap_or_else(|error| cli::fail(error));
+    let moves = Day9.parse(&input).unwrap_or_else(|error| cli::fail(error));
+
+    let mut bridge = Bridge::new(options.knots);
+
+    bridge.run_moves(&moves);
+
+    if options.steps {
+        for (step, frame) in bridge.frames().enumerate() {
+            println!("== step {} ==", step);
+            println!("{}", frame.join("\n"));
+            println!();
+        }
+    } else {
+        println!("{}", bridge.render(bridge.steps()).join("\n"));
+    }
+
+    if let Some(dir) = options.frames {
+        match bridge.write_frames(&dir) {
+            Ok(count) => eprintln!("Wrote {} frames to {}", count, dir.display()),
+            Err(error) => {
+                eprintln!("Unable to write frames to {}: {}", dir.display(), error);
+                process::exit(1);
+            }
+        }
+    }
+}
@@ -0,0 +1,215 @@
+use aoc_common::{parse, Answer, Direction, ParseError, Point, Solution, SolveError};
+use std::collections::HashSet;
+
+mod render;
+
+pub use render::Frames;
+
+fn parse_direction(s: &str) -> Result<Direction, ParseError> {
+    Ok(match s {
+        "U" => Direction::Up,
+        "R" => Direction::Right,
+        "D" => Direction::Down,
+        "L" => Direction::Left,
+        _ => return Err(ParseError::new("expected a direction of U, R, D or L", s)),
+    })
+}
+
+pub type Move = (Direction, u16);
+
+#[derive(Debug)]
+pub struct Bridge {
+    /// Positions of each knot, starting from the head.
+    knots: Vec<Point>,
+    /// Positions of every knot after each step, including the start.
+    history: Vec<Vec<Point>>,
+}
+
+impl Bridge {
+    /// A rope of `length` knots, all starting at the origin.
+    pub fn new(length: usize) -> Self {
+        assert!(length >= 2, "A rope requires at least 2 knots.");
+
+        let knots = vec![Point::ORIGIN; length];
+
+        Bridge {
+            history: vec![knots.clone()],
+            knots,
+        }
+    }
+
+    fn is_touching(a: Point, b: Point) -> bool {
+        a.x.abs_diff(b.x) <= 1 && a.y.abs_diff(b.y) <= 1
+    }
+
+    /// Moves the knot one step towards the one ahead of it, diagonally
+    /// when they aren't in the same row or column.
+    fn follow(knot: Point, ahead: Point) -> Point {
+        if Bridge::is_touching(knot, ahead) {
+            return knot;
+        }
+
+        let offset = ahead - knot;
+
+        knot + Point::new(offset.x.signum(), offset.y.signum())
+    }
+
+    /// Distinct positions visited by the knot, where 0 is the head.
+    pub fn visited(&self, knot: usize) -> HashSet<Point> {
+        self.history.iter().map(|knots| knots[knot]).collect()
+    }
+
+    pub fn count_positions(&self, knot: usize) -> usize {
+        self.visited(knot).len()
+    }
+
+    pub fn count_tail_positions(&self) -> usize {
+        self.count_positions(self.knots.len() - 1)
+    }
+
+    /// The top-left and bottom-right corners of every position
+    /// any knot has been in.
+    pub fn bounds(&self) -> (Point, Point) {
+        self.history
+            .iter()
+            .flatten()
+            .fold((Point::ORIGIN, Point::ORIGIN), |(min, max), knot| {
+                (
+                    Point::new(min.x.min(knot.x), min.y.min(knot.y)),
+                    Point::new(max.x.max(knot.x), max.y.max(knot.y)),
+                )
+            })
+    }
+
+    pub fn run_moves(&mut self, moves: &[Move]) {
+        for (dir, count) in moves {
+            for _ in 1..=*count {
+                self.knots[0] += dir.step();
+
+                for i in 1..self.knots.len() {
+                    self.knots[i] = Bridge::follow(self.knots[i], self.knots[i - 1]);
+                }
+
+                self.history.push(self.knots.clone());
+            }
+        }
+    }
+}
+
+fn parse_moves(data: &str) -> Result<Vec<Move>, ParseError> {
+    parse::lines(data)
+        .map(|line| {
+            let mut parts = line.split_whitespace();
+
+            let (Some(letter), Some(count), None) = (parts.next(), parts.next(), parts.next())
+            else {
+                return Err(ParseError::at(
+                    data,
+                    line,
+                    "expected \"<direction> <steps>\"",
+                ));
+            };
+
+            let direction = parse_direction(letter).map_err(|e| e.locate(data, line))?;
+            let count: u16 = count
+                .parse()
+                .map_err(|_| ParseError::new("expected a step count", count).locate(data, line))?;
+
+            Ok((direction, count))
+        })
+        .collect()
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input<'a> = Vec<Move>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        parse_moves(input)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        let mut bridge = Bridge::new(2);
+
+        bridge.run_moves(input);
+
+        Ok(bridge.count_tail_positions().into())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        let mut bridge = Bridge::new(10);
+
+        bridge.run_moves(input);
+
+        Ok(bridge.count_tail_positions().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::examples!(Day9, "day-9", {
+        example: { part_1: 13, part_2: 1 },
+        larger: { part_2: 36 },
+    });
+
+    #[test]
+    fn moves_example() {
+        let mut bridge = Bridge::new(2);
+        let moves = parse_moves(
+            r#"R 4
+U 4
+L 3
+D 1
+R 4
+D 1
+L 5
+R 2"#,
+        )
+        .unwrap();
+
+        bridge.run_moves(&moves);
+
+        assert_eq!(bridge.count_tail_positions(), 13);
+    }
+
+    #[test]
+    fn long_rope() {
+        let mut bridge = Bridge::new(10);
+        let moves = parse_moves(include_str!("../../../examples/day-9/larger.txt")).unwrap();
+
+        bridge.run_moves(&moves);
+
+        assert_eq!(bridge.count_tail_positions(), 36);
+        assert_eq!(bridge.count_positions(9), 36);
+        // The first knot behind the head trails it closely
+        assert_eq!(bridge.count_positions(1), 88);
+        assert_eq!(bridge.bounds(), (Point::new(-11, -15), Point::new(14, 5)));
+    }
+
+    #[test]
+    fn unbounded() {
+        let mut bridge = Bridge::new(2);
+
+        bridge.run_moves(&parse_moves("L 1000\nU 1000\n").unwrap());
+
+        assert_eq!(bridge.knots[0], Point::new(-1000, -1000));
+        assert_eq!(bridge.knots[1], Point::new(-1000, -999));
+        assert_eq!(bridge.count_tail_positions(), 1999);
+        assert_eq!(bridge.bounds(), (Point::new(-1000, -1000), Point::ORIGIN));
+    }
+
+    #[test]
+    fn invalid_direction() {
+        assert_eq!(
+            parse_moves("R 4\nX 2\n").unwrap_err().to_string(),
+            "line 2: expected a direction of U, R, D or L, found \"X\""
+        );
+        assert_eq!(
+            parse_moves("R 4\nU x\n").unwrap_err().to_string(),
+            "line 2, column 3: expected a step count, found \"x\""
+        );
+    }
+}
@@ -0,0 +1,168 @@
+use crate::Bridge;
+use aoc_common::Point;
+use std::{fs, io, path::Path};
+
+impl Bridge {
+    /// Number of steps taken, where step 0 is the starting state.
+    pub fn steps(&self) -> usize {
+        self.history.len() - 1
+    }
+
+    /// The character a knot is drawn with, following the puzzle's notation.
+    fn label(&self, knot: usize) -> char {
+        if knot == 0 {
+            'H'
+        } else if self.knots.len() == 2 {
+            'T'
+        } else {
+            char::from_digit(knot as u32 % 36, 36).unwrap_or('?')
+        }
+    }
+
+    /// Draws the rope after the step, over the start (`s`) and the cells
+    /// the tail has visited so far (`#`). When knots overlap, the one
+    /// closest to the head is drawn. Every frame shares the same bounds.
+    pub fn render(&self, step: usize) -> Vec<String> {
+        let step = step.min(self.steps());
+        let mut frames = self.frames();
+
+        for knots in &self.history[..step] {
+            frames.mark(knots);
+        }
+
+        frames.step = step;
+        frames.next().unwrap_or_default()
+    }
+
+    /// Every step drawn as by `render`, in order. The bounds are found once
+    /// and the visited cells are marked on a shared background a step at a
+    /// time, so each frame only copies the background and draws the knots.
+    pub fn frames(&self) -> Frames<'_> {
+        let (min, max) = self.bounds();
+        let mut background =
+            vec![vec!['.'; (max.x - min.x + 1) as usize]; (max.y - min.y + 1) as usize];
+
+        background[(-min.y) as usize][(-min.x) as usize] = 's';
+
+        Frames {
+            bridge: self,
+            min,
+            background,
+            step: 0,
+        }
+    }
+
+    /// Writes every step to `step-NNNNN.txt` within the directory,
+    /// returning the number of frames written.
+    pub fn write_frames(&self, dir: &Path) -> io::Result<usize> {
+        fs::create_dir_all(dir)?;
+
+        for (step, frame) in self.frames().enumerate() {
+            let mut frame = frame.join("\n");
+            frame.push('\n');
+
+            fs::write(dir.join(format!("step-{:05}.txt", step)), frame)?;
+        }
+
+        Ok(self.steps() + 1)
+    }
+}
+
+pub struct Frames<'b> {
+    bridge: &'b Bridge,
+    min: Point,
+    /// The start and the cells the tail has visited, without the rope.
+    background: Vec<Vec<char>>,
+    step: usize,
+}
+
+impl Frames<'_> {
+    fn cell(&self, point: Point) -> (usize, usize) {
+        (
+            (point.y - self.min.y) as usize,
+            (point.x - self.min.x) as usize,
+        )
+    }
+
+    /// Marks the tail's cell as visited, keeping the start visible.
+    fn mark(&mut self, knots: &[Point]) {
+        let tail = knots[knots.len() - 1];
+
+        if tail != Point::ORIGIN {
+            let (row, column) = self.cell(tail);
+
+            self.background[row][column] = '#';
+        }
+    }
+}
+
+impl Iterator for Frames<'_> {
+    type Item = Vec<String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let knots = self.bridge.history.get(self.step)?;
+
+        self.mark(knots);
+
+        let mut frame = self.background.clone();
+
+        // Draw from the tail so knots closer to the head end up on top
+        for (knot, point) in knots.iter().enumerate().rev() {
+            let (row, column) = self.cell(*point);
+
+            frame[row][column] = self.bridge.label(knot);
+        }
+
+        self.step += 1;
+
+        Some(frame.into_iter().map(String::from_iter).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse_moves, Bridge};
+
+    #[test]
+    fn renders_short_rope() {
+        let mut bridge = Bridge::new(2);
+
+        bridge.run_moves(&parse_moves("R 4\nU 4\nL 3\nD 1\nR 4\nD 1\nL 5\nR 2\n").unwrap());
+
+        assert_eq!(
+            bridge.render(4),
+            vec!["......", "......", "......", "......", "s##TH.",]
+        );
+        assert_eq!(
+            bridge.render(bridge.steps()),
+            vec!["..##..", "...##.", ".TH##.", "....#.", "s###..",]
+        );
+    }
+
+    #[test]
+    fn renders_long_rope() {
+        let mut bridge = Bridge::new(10);
+
+        bridge.run_moves(&parse_moves("R 4\nU 4\n").unwrap());
+
+        assert_eq!(
+            bridge.render(bridge.steps()),
+            vec!["....H", "....1", "..432", ".5...", "6...."]
+        );
+    }
+
+    #[test]
+    fn frames_match_render() {
+        let mut bridge = Bridge::new(2);
+
+        bridge.run_moves(&parse_moves("R 4\nU 4\nL 3\nD 1\nR 4\nD 1\nL 5\nR 2\n").unwrap());
+
+        let frames = bridge.frames().collect::<Vec<_>>();
+
+        assert_eq!(frames.len(), bridge.steps() + 1);
+
+        for (step, frame) in frames.iter().enumerate() {
+            assert_eq!(frame, &bridge.render(step));
+        }
+    }
+}
@@ -0,0 +1,19 @@
+use aoc_common::{Answer, ParseError, Solution, SolveError};
+
+pub struct DayBase;
+
+impl Solution for DayBase {
+    type Input<'a> = &'a str;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        Ok(input)
+    }
+
+    fn part_1(&self, _input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(Answer::Unsolved)
+    }
+
+    fn part_2(&self, _input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(Answer::Unsolved)
+    }
+}
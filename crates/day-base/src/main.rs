@@ -1,21 +0,0 @@
-use std::time::Instant;
-
-fn part_1() {
-    let data = include_str!("input.txt");
-    let time = Instant::now();
-
-    println!("{:?}", time.elapsed());
-}
-
-fn part_2() {
-    let data = include_str!("input.txt");
-    let time = Instant::now();
-
-    println!("{:?}", time.elapsed());
-}
-
-fn main() {
-    part_1();
-    println!("-----");
-    part_2();
-}
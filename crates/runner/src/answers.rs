@@ -0,0 +1,341 @@
+use aoc_common::{Answer, ParseError};
+use std::path::{Path, PathBuf};
+
+pub const DEFAULT_ANSWERS_FILE: &str = "answers.toml";
+
+/// The expected answer of a part for a specific input file.
+#[derive(Clone, Debug, PartialEq)]
+pub struct KnownAnswer {
+    pub day: u8,
+    pub part: u8,
+    pub input: PathBuf,
+    pub expected: Answer,
+}
+
+#[derive(Debug, PartialEq)]
+enum Value {
+    Integer(i128),
+    String(String),
+    Array(Vec<Value>),
+}
+
+#[derive(Default)]
+struct Table<'l> {
+    header: &'l str,
+    day: Option<u8>,
+    part: Option<u8>,
+    input: Option<String>,
+    expected: Option<Answer>,
+}
+
+/// Loads answers from a TOML file of `[[answer]]` tables. Input paths are
+/// resolved relative to the directory containing the file.
+pub fn load_answers(path: &Path) -> Result<Vec<KnownAnswer>, String> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|error| format!("Unable to read {}: {}", path.display(), error))?;
+    let base = path.parent().unwrap_or_else(|| Path::new(""));
+
+    parse_answers(&content, base).map_err(|error| format!("{}: {}", path.display(), error))
+}
+
+/// Parses the small subset of TOML used by the answers file: `[[answer]]`
+/// tables whose keys hold integers, strings, or arrays of strings (for
+/// multi-line answers). Arrays may span multiple lines.
+pub fn parse_answers(content: &str, base: &Path) -> Result<Vec<KnownAnswer>, ParseError> {
+    let mut answers = vec![];
+    let mut table: Option<Table> = None;
+    let mut lines = content.lines();
+
+    while let Some(line) = lines.next() {
+        let trimmed = strip_comment(line).trim();
+
+        if trimmed.is_empty() {
+            continue;
+        }
+
+        if trimmed == "[[answer]]" {
+            if let Some(table) = table.take() {
+                answers.push(finish_table(content, table, base)?);
+            }
+
+            table = Some(Table {
+                header: line,
+                ..Table::default()
+            });
+
+            continue;
+        }
+
+        let Some(current) = table.as_mut() else {
+            return Err(ParseError::at(
+                content,
+                line,
+                "expected an [[answer]] table",
+            ));
+        };
+
+        let (key, raw_value) = trimmed
+            .split_once('=')
+            .ok_or_else(|| ParseError::at(content, line, "expected \"key = value\""))?;
+        let mut raw_value = raw_value.trim().to_owned();
+
+        // Arrays continue until their closing bracket
+        while raw_value.starts_with('[') && !is_array_closed(&raw_value) {
+            let next = lines
+                .next()
+                .ok_or_else(|| ParseError::at(content, line, "unclosed array"))?;
+
+            raw_value.push(' ');
+            raw_value.push_str(strip_comment(next).trim());
+        }
+
+        let value = parse_value(&raw_value).map_err(|e| e.locate(content, line))?;
+        let invalid = |expected: &str| ParseError::at(content, line, expected.to_owned());
+
+        match key.trim() {
+            "day" => match value {
+                Value::Integer(day) if (1..=25).contains(&day) => current.day = Some(day as u8),
+                _ => return Err(invalid("expected a day between 1 and 25")),
+            },
+            "part" => match value {
+                Value::Integer(part) if part == 1 || part == 2 => current.part = Some(part as u8),
+                _ => return Err(invalid("expected a part of 1 or 2")),
+            },
+            "input" => match value {
+                Value::String(input) => current.input = Some(input),
+                _ => return Err(invalid("expected an input file path")),
+            },
+            "expected" => {
+                current.expected =
+                    Some(value_to_answer(value).ok_or_else(|| {
+                        invalid("expected an integer, string, or array of strings")
+                    })?)
+            }
+            _ => return Err(ParseError::at(content, line, "unknown key")),
+        }
+    }
+
+    if let Some(table) = table.take() {
+        answers.push(finish_table(content, table, base)?);
+    }
+
+    Ok(answers)
+}
+
+fn finish_table(content: &str, table: Table, base: &Path) -> Result<KnownAnswer, ParseError> {
+    let missing = |key: &str| ParseError::at(content, table.header, format!("missing \"{}\"", key));
+
+    Ok(KnownAnswer {
+        day: table.day.ok_or_else(|| missing("day"))?,
+        part: table.part.ok_or_else(|| missing("part"))?,
+        input: base.join(table.input.as_ref().ok_or_else(|| missing("input"))?),
+        expected: table.expected.ok_or_else(|| missing("expected"))?,
+    })
+}
+
+fn value_to_answer(value: Value) -> Option<Answer> {
+    match value {
+        Value::Integer(value) => Some(Answer::Integer(value)),
+        Value::String(value) => Some(Answer::Text(value)),
+        Value::Array(values) => values
+            .into_iter()
+            .map(|value| match value {
+                Value::String(row) => Some(row),
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>()
+            .map(Answer::Art),
+    }
+}
+
+fn strip_comment(line: &str) -> &str {
+    let mut in_string = false;
+    let mut escaped = false;
+
+    for (index, c) in line.char_indices() {
+        if escaped {
+            escaped = false;
+            continue;
+        }
+
+        match c {
+            '\\' if in_string => escaped = true,
+            '"' => in_string = !in_string,
+            '#' if !in_string => return &line[..index],
+            _ => {}
+        }
+    }
+
+    line
+}
+
+fn is_array_closed(value: &str) -> bool {
+    let mut depth = 0;
+    let mut in_string = false;
+    let mut escaped = false;
+
+    for c in value.chars() {
+        if escaped {
+            escaped = false;
+            continue;
+        }
+
+        match c {
+            '\\' if in_string => escaped = true,
+            '"' => in_string = !in_string,
+            '[' if !in_string => depth += 1,
+            ']' if !in_string => depth -= 1,
+            _ => {}
+        }
+    }
+
+    depth == 0
+}
+
+fn parse_value(raw: &str) -> Result<Value, ParseError> {
+    let (value, rest) = parse_value_prefix(raw.trim())?;
+
+    if rest.trim().is_empty() {
+        Ok(value)
+    } else {
+        Err(ParseError::new(
+            "unexpected trailing characters",
+            rest.trim(),
+        ))
+    }
+}
+
+fn parse_value_prefix(raw: &str) -> Result<(Value, &str), ParseError> {
+    if let Some(rest) = raw.strip_prefix('"') {
+        let mut value = String::new();
+        let mut chars = rest.char_indices();
+
+        while let Some((index, c)) = chars.next() {
+            match c {
+                '"' => return Ok((Value::String(value), &rest[index + 1..])),
+                '\\' => match chars.next() {
+                    Some((_, 'n')) => value.push('\n'),
+                    Some((_, 't')) => value.push('\t'),
+                    Some((_, '"')) => value.push('"'),
+                    Some((_, '\\')) => value.push('\\'),
+                    _ => return Err(ParseError::new("unsupported escape sequence", raw)),
+                },
+                c => value.push(c),
+            }
+        }
+
+        return Err(ParseError::new("unterminated string", raw));
+    }
+
+    if let Some(mut rest) = raw.strip_prefix('[') {
+        let mut values = vec![];
+
+        loop {
+            rest = rest.trim_start();
+
+            if let Some(after) = rest.strip_prefix(']') {
+                return Ok((Value::Array(values), after));
+            }
+
+            let (value, after) = parse_value_prefix(rest)?;
+            values.push(value);
+            rest = after.trim_start();
+
+            if let Some(after) = rest.strip_prefix(',') {
+                rest = after;
+            } else if !rest.starts_with(']') {
+                return Err(ParseError::new("expected \",\" or \"]\"", rest));
+            }
+        }
+    }
+
+    let end = raw
+        .find(|c: char| !(c.is_ascii_digit() || c == '-' || c == '_'))
+        .unwrap_or(raw.len());
+    let number = raw[..end].replace('_', "");
+
+    number
+        .parse()
+        .map(|value| (Value::Integer(value), &raw[end..]))
+        .map_err(|_| ParseError::new("expected an integer, string, or array", raw))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_tables() {
+        let content = r###"
+# Comment
+[[answer]]
+day = 10
+part = 1
+input = "inputs/day-10.txt" # trailing comment
+expected = 13_140
+
+[[answer]]
+day = 5
+part = 2
+input = "examples/day-05.txt"
+expected = "MCD"
+
+[[answer]]
+day = 10
+part = 2
+input = "inputs/day-10.txt"
+expected = [
+    "##..",
+    "#.#.", # second row
+]
+"###;
+        let answers = parse_answers(content, Path::new("root")).unwrap();
+
+        assert_eq!(
+            answers,
+            vec![
+                KnownAnswer {
+                    day: 10,
+                    part: 1,
+                    input: PathBuf::from("root/inputs/day-10.txt"),
+                    expected: Answer::Integer(13140),
+                },
+                KnownAnswer {
+                    day: 5,
+                    part: 2,
+                    input: PathBuf::from("root/examples/day-05.txt"),
+                    expected: Answer::Text(String::from("MCD")),
+                },
+                KnownAnswer {
+                    day: 10,
+                    part: 2,
+                    input: PathBuf::from("root/inputs/day-10.txt"),
+                    expected: Answer::Art(vec![String::from("##.."), String::from("#.#.")]),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let base = Path::new("");
+
+        assert_eq!(
+            parse_answers("day = 1", base).unwrap_err().to_string(),
+            "line 1: expected an [[answer]] table, found \"day = 1\""
+        );
+        assert_eq!(
+            parse_answers("[[answer]]\nday = 1\npart = 3", base)
+                .unwrap_err()
+                .to_string(),
+            "line 3: expected a part of 1 or 2, found \"part = 3\""
+        );
+        assert_eq!(
+            parse_answers("[[answer]]\nday = 1\npart = 1\ninput = \"x\"", base)
+                .unwrap_err()
+                .to_string(),
+            "line 1: missing \"expected\", found \"[[answer]]\""
+        );
+        assert!(parse_answers("[[answer]]\nexpected = \"open", base).is_err());
+    }
+}
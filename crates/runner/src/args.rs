@@ -0,0 +1,400 @@
+use aoc::{bench::BenchOptions, input::InputSource};
+use aoc_common::cli::{self, Args};
+use std::path::PathBuf;
+
+pub const USAGE: &str = "Usage:
+  aoc run [--day <N>] [--part <1|2>] [--input <FILE|->] [--inputs-dir <DIR>] [--json]
+  aoc verify [--answers <FILE>]
+  aoc new <DAY> [--root <DIR>]
+  aoc bench [--day <N>] [--part <1|2>] [--input <FILE|->] [--inputs-dir <DIR>]
+            [--runs <N>] [--warmup <N>] [--save <FILE>] [--baseline <FILE>] [--threshold <PCT>]";
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Run {
+        day: Option<u8>,
+        part: Option<u8>,
+        /// When not provided, the inputs directory is resolved from the environment.
+        input: Option<InputSource>,
+        json: bool,
+    },
+    Verify {
+        answers: Option<PathBuf>,
+    },
+    New {
+        day: u8,
+        /// Workspace root, defaults to the current directory.
+        root: PathBuf,
+    },
+    Bench {
+        day: Option<u8>,
+        part: Option<u8>,
+        input: Option<InputSource>,
+        options: BenchOptions,
+        /// File to write the median timings to.
+        save: Option<PathBuf>,
+        /// File of previous timings to flag regressions against.
+        baseline: Option<PathBuf>,
+        /// Percentage slowdown that counts as a regression.
+        threshold: f64,
+    },
+}
+
+/// Handles the day, part and input flags shared by `run` and `bench`.
+/// Returns false if the argument is not one of them.
+fn parse_selection<I: Iterator<Item = String>>(
+    arg: &str,
+    args: &mut Args<I>,
+    day: &mut Option<u8>,
+    part: &mut Option<u8>,
+    input: &mut Option<InputSource>,
+) -> Result<bool, String> {
+    match arg {
+        "--day" | "-d" => {
+            *day = Some(args.parse(arg)?);
+        }
+        "--part" | "-p" => {
+            let value: u8 = args.parse(arg)?;
+
+            if value != 1 && value != 2 {
+                return Err(format!("Part must be 1 or 2, received {}", value));
+            }
+
+            *part = Some(value);
+        }
+        "--input" | "-i" => {
+            let path = PathBuf::from(args.value(arg)?);
+
+            *input = Some(if path.as_os_str() == "-" {
+                InputSource::Stdin
+            } else {
+                InputSource::File(path)
+            });
+        }
+        "--inputs-dir" => {
+            *input = Some(InputSource::Directory(PathBuf::from(args.value(arg)?)));
+        }
+        _ => return Ok(false),
+    }
+
+    Ok(true)
+}
+
+fn check_selection(day: Option<u8>, input: &Option<InputSource>) -> Result<(), String> {
+    if day.is_none() && matches!(input, Some(InputSource::File(_) | InputSource::Stdin)) {
+        return Err(String::from("--input requires --day"));
+    }
+
+    Ok(())
+}
+
+pub fn parse_args<I: Iterator<Item = String>>(mut args: I) -> Result<Command, String> {
+    let command = args.next();
+    let mut args = Args::new(args);
+
+    match command.as_deref() {
+        Some("run") => {
+            let mut day = None;
+            let mut part = None;
+            let mut input = None;
+            let mut json = false;
+
+            while let Some(arg) = args.next_flag()? {
+                if parse_selection(&arg, &mut args, &mut day, &mut part, &mut input)? {
+                    continue;
+                }
+
+                match arg.as_str() {
+                    "--json" => {
+                        json = true;
+                    }
+                    unknown => {
+                        return Err(cli::unknown_argument(unknown));
+                    }
+                }
+            }
+
+            args.no_input()?;
+            check_selection(day, &input)?;
+
+            Ok(Command::Run {
+                day,
+                part,
+                input,
+                json,
+            })
+        }
+        Some("verify") => {
+            let mut answers = None;
+
+            while let Some(arg) = args.next_flag()? {
+                match arg.as_str() {
+                    "--answers" | "-a" => {
+                        answers = Some(PathBuf::from(args.value(&arg)?));
+                    }
+                    unknown => {
+                        return Err(cli::unknown_argument(unknown));
+                    }
+                }
+            }
+
+            args.no_input()?;
+
+            Ok(Command::Verify { answers })
+        }
+        Some("new") => {
+            let mut root = PathBuf::from(".");
+
+            while let Some(arg) = args.next_flag()? {
+                match arg.as_str() {
+                    "--root" => {
+                        root = PathBuf::from(args.value(&arg)?);
+                    }
+                    unknown => {
+                        return Err(cli::unknown_argument(unknown));
+                    }
+                }
+            }
+
+            let day = args
+                .input()
+                .map_err(|_| String::from("Missing value for <DAY>"))?;
+
+            match day.parse() {
+                Ok(day) if (1..=25).contains(&day) => Ok(Command::New { day, root }),
+                _ => Err(format!("Day must be between 1 and 25, received {}", day)),
+            }
+        }
+        Some("bench") => {
+            let mut day = None;
+            let mut part = None;
+            let mut input = None;
+            let mut options = BenchOptions::default();
+            let mut save = None;
+            let mut baseline = None;
+            let mut threshold = 10.0;
+
+            while let Some(arg) = args.next_flag()? {
+                if parse_selection(&arg, &mut args, &mut day, &mut part, &mut input)? {
+                    continue;
+                }
+
+                match arg.as_str() {
+                    "--runs" | "-n" => {
+                        options.runs = args.parse(&arg)?;
+
+                        if options.runs == 0 {
+                            return Err(String::from("--runs must be at least 1"));
+                        }
+                    }
+                    "--warmup" => {
+                        options.warmup = args.parse(&arg)?;
+                    }
+                    "--save" => {
+                        save = Some(PathBuf::from(args.value(&arg)?));
+                    }
+                    "--baseline" => {
+                        baseline = Some(PathBuf::from(args.value(&arg)?));
+                    }
+                    "--threshold" => {
+                        let value = args.value(&arg)?;
+
+                        threshold = value
+                            .trim_end_matches('%')
+                            .parse()
+                            .ok()
+                            .filter(|value: &f64| *value >= 0.0)
+                            .ok_or_else(|| format!("Invalid value for {}: {}", arg, value))?;
+                    }
+                    unknown => {
+                        return Err(cli::unknown_argument(unknown));
+                    }
+                }
+            }
+
+            args.no_input()?;
+            check_selection(day, &input)?;
+
+            Ok(Command::Bench {
+                day,
+                part,
+                input,
+                options,
+                save,
+                baseline,
+                threshold,
+            })
+        }
+        Some(unknown) => Err(format!("Unknown command {}", unknown)),
+        None => Err(String::from("Missing command")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Command, String> {
+        parse_args(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn run_all() {
+        assert_eq!(
+            parse(&["run"]),
+            Ok(Command::Run {
+                day: None,
+                part: None,
+                input: None,
+                json: false,
+            })
+        );
+    }
+
+    #[test]
+    fn run_day_part() {
+        assert_eq!(
+            parse(&["run", "--day", "7", "--part", "2"]),
+            Ok(Command::Run {
+                day: Some(7),
+                part: Some(2),
+                input: None,
+                json: false,
+            })
+        );
+    }
+
+    #[test]
+    fn run_with_input() {
+        assert_eq!(
+            parse(&["run", "-d", "3", "--input", "custom.txt"]),
+            Ok(Command::Run {
+                day: Some(3),
+                part: None,
+                input: Some(InputSource::File(PathBuf::from("custom.txt"))),
+                json: false,
+            })
+        );
+        assert_eq!(
+            parse(&["run", "-d", "3", "--input", "-"]),
+            Ok(Command::Run {
+                day: Some(3),
+                part: None,
+                input: Some(InputSource::Stdin),
+                json: false,
+            })
+        );
+        assert_eq!(
+            parse(&["run", "--inputs-dir", "other"]),
+            Ok(Command::Run {
+                day: None,
+                part: None,
+                input: Some(InputSource::Directory(PathBuf::from("other"))),
+                json: false,
+            })
+        );
+    }
+
+    #[test]
+    fn run_as_json() {
+        assert_eq!(
+            parse(&["run", "--json"]),
+            Ok(Command::Run {
+                day: None,
+                part: None,
+                input: None,
+                json: true,
+            })
+        );
+    }
+
+    #[test]
+    fn verify() {
+        assert_eq!(parse(&["verify"]), Ok(Command::Verify { answers: None }));
+        assert_eq!(
+            parse(&["verify", "--answers", "other.toml"]),
+            Ok(Command::Verify {
+                answers: Some(PathBuf::from("other.toml"))
+            })
+        );
+    }
+
+    #[test]
+    fn new() {
+        assert_eq!(
+            parse(&["new", "12"]),
+            Ok(Command::New {
+                day: 12,
+                root: PathBuf::from("."),
+            })
+        );
+        assert_eq!(
+            parse(&["new", "--root", "other", "3"]),
+            Ok(Command::New {
+                day: 3,
+                root: PathBuf::from("other"),
+            })
+        );
+    }
+
+    #[test]
+    fn bench() {
+        assert_eq!(
+            parse(&["bench", "-d", "7"]),
+            Ok(Command::Bench {
+                day: Some(7),
+                part: None,
+                input: None,
+                options: BenchOptions::default(),
+                save: None,
+                baseline: None,
+                threshold: 10.0,
+            })
+        );
+        assert_eq!(
+            parse(&[
+                "bench",
+                "--runs",
+                "50",
+                "--warmup",
+                "0",
+                "--save",
+                "new.txt",
+                "--baseline",
+                "old.txt",
+                "--threshold",
+                "25%",
+            ]),
+            Ok(Command::Bench {
+                day: None,
+                part: None,
+                input: None,
+                options: BenchOptions {
+                    runs: 50,
+                    warmup: 0,
+                },
+                save: Some(PathBuf::from("new.txt")),
+                baseline: Some(PathBuf::from("old.txt")),
+                threshold: 25.0,
+            })
+        );
+    }
+
+    #[test]
+    fn errors() {
+        assert!(parse(&[]).is_err());
+        assert!(parse(&["walk"]).is_err());
+        assert!(parse(&["run", "--day"]).is_err());
+        assert!(parse(&["run", "--day", "x"]).is_err());
+        assert!(parse(&["run", "--part", "3"]).is_err());
+        assert!(parse(&["run", "--input", "custom.txt"]).is_err());
+        assert!(parse(&["new"]).is_err());
+        assert!(parse(&["new", "26"]).is_err());
+        assert!(parse(&["new", "1", "2"]).is_err());
+        assert!(parse(&["bench", "--runs", "0"]).is_err());
+        assert!(parse(&["bench", "--threshold", "-5"]).is_err());
+        assert!(parse(&["bench", "--json"]).is_err());
+        assert!(parse(&["verify", "answers.toml"]).is_err());
+        assert!(parse(&["run", "7"]).is_err());
+    }
+}
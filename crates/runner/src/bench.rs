@@ -0,0 +1,296 @@
+use aoc_common::{Solver, SolverError};
+use std::{collections::BTreeMap, fmt, time::Duration};
+
+/// Changes smaller than this are timer noise, regardless of the threshold.
+const NOISE_FLOOR: Duration = Duration::from_micros(1);
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BenchOptions {
+    pub runs: usize,
+    pub warmup: usize,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        BenchOptions {
+            runs: 100,
+            warmup: 10,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    pub fn new(samples: &mut [Duration]) -> Self {
+        if samples.is_empty() {
+            return Stats::default();
+        }
+
+        samples.sort();
+
+        // Nearest-rank percentiles
+        let rank = |percentile: usize| {
+            let index = (percentile * samples.len()).div_ceil(100);
+
+            samples[index.saturating_sub(1).min(samples.len() - 1)]
+        };
+
+        Stats {
+            min: samples[0],
+            median: rank(50),
+            p95: rank(95),
+        }
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:>10.2?} {:>10.2?} {:>10.2?}",
+            self.min, self.median, self.p95
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct BenchResult {
+    pub day: u8,
+    pub part: u8,
+    pub parse: Stats,
+    pub solve: Stats,
+}
+
+impl BenchResult {
+    pub fn median(&self) -> Duration {
+        self.parse.median + self.solve.median
+    }
+}
+
+pub fn bench_part(
+    day: u8,
+    part: u8,
+    solution: &dyn Solver,
+    input: &str,
+    options: &BenchOptions,
+) -> Result<BenchResult, SolverError> {
+    for _ in 0..options.warmup {
+        solution.solve_timed(input, part)?;
+    }
+
+    let mut parse = Vec::with_capacity(options.runs);
+    let mut solve = Vec::with_capacity(options.runs);
+
+    for _ in 0..options.runs.max(1) {
+        let timing = solution.solve_timed(input, part)?;
+
+        parse.push(timing.parse);
+        solve.push(timing.solve);
+    }
+
+    Ok(BenchResult {
+        day,
+        part,
+        parse: Stats::new(&mut parse),
+        solve: Stats::new(&mut solve),
+    })
+}
+
+/// Median parse and solve times of previous runs, keyed by day and part.
+#[derive(Debug, Default, PartialEq)]
+pub struct Baseline {
+    pub entries: BTreeMap<(u8, u8), (Duration, Duration)>,
+}
+
+impl Baseline {
+    pub fn from_results(results: &[BenchResult]) -> Self {
+        Baseline {
+            entries: results
+                .iter()
+                .map(|result| {
+                    (
+                        (result.day, result.part),
+                        (result.parse.median, result.solve.median),
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    pub fn parse(content: &str) -> Result<Self, String> {
+        let mut baseline = Baseline::default();
+
+        for (index, line) in content.lines().enumerate() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let values = line
+                .split_whitespace()
+                .map(|value| value.parse::<u64>().ok())
+                .collect::<Option<Vec<_>>>();
+
+            match values.as_deref() {
+                Some(&[day, part, parse, solve])
+                    if (1..=25).contains(&day) && (1..=2).contains(&part) =>
+                {
+                    baseline.entries.insert(
+                        (day as u8, part as u8),
+                        (Duration::from_nanos(parse), Duration::from_nanos(solve)),
+                    );
+                }
+                _ => {
+                    return Err(format!(
+                        "line {}: expected \"<day> <part> <parse_ns> <solve_ns>\", found {:?}",
+                        index + 1,
+                        line
+                    ));
+                }
+            }
+        }
+
+        Ok(baseline)
+    }
+
+    pub fn compare(&self, result: &BenchResult, threshold: f64) -> Option<Comparison> {
+        let (parse, solve) = self.entries.get(&(result.day, result.part))?;
+        let previous = *parse + *solve;
+        let current = result.median();
+        let change = if previous.is_zero() {
+            0.0
+        } else {
+            (current.as_secs_f64() - previous.as_secs_f64()) / previous.as_secs_f64() * 100.0
+        };
+
+        Some(Comparison {
+            previous,
+            change,
+            regressed: change > threshold && current.saturating_sub(previous) > NOISE_FLOOR,
+        })
+    }
+}
+
+impl fmt::Display for Baseline {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "# day part parse_ns solve_ns")?;
+
+        for ((day, part), (parse, solve)) in &self.entries {
+            writeln!(
+                f,
+                "{} {} {} {}",
+                day,
+                part,
+                parse.as_nanos(),
+                solve.as_nanos()
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Comparison {
+    pub previous: Duration,
+    /// Percentage change of the median, where positive is slower.
+    pub change: f64,
+    pub regressed: bool,
+}
+
+impl fmt::Display for Comparison {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {:+.1}% vs {:.2?}",
+            if self.regressed { "REGRESSED" } else { "ok" },
+            self.change,
+            self.previous
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn micros(values: &[u64]) -> Vec<Duration> {
+        values
+            .iter()
+            .map(|value| Duration::from_micros(*value))
+            .collect()
+    }
+
+    fn result(parse: u64, solve: u64) -> BenchResult {
+        BenchResult {
+            day: 7,
+            part: 1,
+            parse: Stats {
+                median: Duration::from_micros(parse),
+                ..Stats::default()
+            },
+            solve: Stats {
+                median: Duration::from_micros(solve),
+                ..Stats::default()
+            },
+        }
+    }
+
+    #[test]
+    fn stats() {
+        let mut samples = micros(&[5, 1, 4, 2, 3]);
+
+        assert_eq!(
+            Stats::new(&mut samples),
+            Stats {
+                min: Duration::from_micros(1),
+                median: Duration::from_micros(3),
+                p95: Duration::from_micros(5),
+            }
+        );
+
+        let mut samples = micros(&(1..=100).collect::<Vec<_>>());
+        let stats = Stats::new(&mut samples);
+
+        assert_eq!(stats.median, Duration::from_micros(50));
+        assert_eq!(stats.p95, Duration::from_micros(95));
+        assert_eq!(Stats::new(&mut []), Stats::default());
+    }
+
+    #[test]
+    fn baseline_round_trip() {
+        let baseline = Baseline::from_results(&[result(10, 20)]);
+        let content = baseline.to_string();
+
+        assert_eq!(content, "# day part parse_ns solve_ns\n7 1 10000 20000\n");
+        assert_eq!(Baseline::parse(&content), Ok(baseline));
+        assert!(Baseline::parse("7 1 abc 20").is_err());
+        assert!(Baseline::parse("7 3 10 20").is_err());
+        assert!(Baseline::parse("0 1 10 20").is_err());
+        assert!(Baseline::parse("7 0 10 20").is_err());
+        assert!(Baseline::parse("26 1 10 20").is_err());
+    }
+
+    #[test]
+    fn flags_regressions() {
+        let baseline = Baseline::from_results(&[result(10, 90)]);
+
+        let comparison = baseline.compare(&result(10, 100), 10.0).unwrap();
+        assert!(!comparison.regressed);
+        assert!((comparison.change - 10.0).abs() < 1e-9);
+
+        assert!(baseline.compare(&result(10, 101), 10.0).unwrap().regressed);
+        assert!(!baseline.compare(&result(5, 50), 10.0).unwrap().regressed);
+
+        let mut other = result(10, 90);
+        other.day = 8;
+        assert_eq!(baseline.compare(&other, 10.0), None);
+    }
+}
@@ -0,0 +1,105 @@
+use std::{
+    env, fmt,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+pub const DEFAULT_INPUTS_DIR: &str = "inputs";
+
+pub const INPUTS_DIR_ENV: &str = "AOC_INPUTS";
+
+#[derive(Debug, PartialEq)]
+pub enum InputSource {
+    /// Read `day-NN.txt` from the directory.
+    Directory(PathBuf),
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// The inputs directory from `AOC_INPUTS`, falling back to `./inputs`.
+    pub fn from_env() -> Self {
+        InputSource::Directory(
+            env::var_os(INPUTS_DIR_ENV)
+                .map(PathBuf::from)
+                .unwrap_or_else(|| PathBuf::from(DEFAULT_INPUTS_DIR)),
+        )
+    }
+
+    pub fn load(&self, day: u8) -> Result<String, InputError> {
+        match self {
+            InputSource::Directory(dir) => read_file(&input_path(dir, day)),
+            InputSource::File(path) => read_file(path),
+            InputSource::Stdin => {
+                let mut buffer = String::new();
+
+                io::stdin()
+                    .read_to_string(&mut buffer)
+                    .map_err(InputError::Stdin)?;
+
+                Ok(buffer)
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    Missing(PathBuf),
+    Read(PathBuf, io::Error),
+    Stdin(io::Error),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::Missing(path) => {
+                write!(f, "Missing input file {}", path.display())
+            }
+            InputError::Read(path, error) => {
+                write!(f, "Unable to read input file {}: {}", path.display(), error)
+            }
+            InputError::Stdin(error) => {
+                write!(f, "Unable to read input from stdin: {}", error)
+            }
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+pub fn input_path(dir: &Path, day: u8) -> PathBuf {
+    dir.join(format!("day-{:02}.txt", day))
+}
+
+fn read_file(path: &Path) -> Result<String, InputError> {
+    if !path.is_file() {
+        return Err(InputError::Missing(path.to_path_buf()));
+    }
+
+    std::fs::read_to_string(path).map_err(|error| InputError::Read(path.to_path_buf(), error))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pads_day_number() {
+        assert_eq!(
+            input_path(Path::new("inputs"), 7),
+            PathBuf::from("inputs/day-07.txt")
+        );
+        assert_eq!(
+            input_path(Path::new("inputs"), 11),
+            PathBuf::from("inputs/day-11.txt")
+        );
+    }
+
+    #[test]
+    fn missing_file() {
+        let source = InputSource::Directory(PathBuf::from("does-not-exist"));
+
+        assert!(matches!(source.load(1), Err(InputError::Missing(_))));
+    }
+}
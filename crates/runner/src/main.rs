@@ -0,0 +1,286 @@
+mod args;
+
+use aoc::{
+    answers::{load_answers, DEFAULT_ANSWERS_FILE},
+    bench::{bench_part, Baseline, BenchOptions, BenchResult},
+    input::InputSource,
+    registry::{find_solution, SOLUTIONS},
+    scaffold::scaffold,
+    verify::{verify, Summary},
+};
+use aoc_common::{Solver, SolverError, Timing};
+use args::{parse_args, Command, USAGE};
+use std::{fs, path::PathBuf, process};
+
+/// Returns false when the part couldn't be answered.
+fn run_part(day: u8, part: u8, timing: Result<Timing, SolverError>, json: bool) -> bool {
+    let timing = match timing {
+        Ok(timing) => timing,
+        Err(error) => {
+            eprintln!("day {} part {}: {}", day, part, error);
+            return false;
+        }
+    };
+    let answer = timing.answer;
+    let elapsed = timing.parse + timing.solve;
+
+    if json {
+        println!(
+            "{{\"day\":{},\"part\":{},\"answer\":{},\"parse_ns\":{},\"solve_ns\":{}}}",
+            day,
+            part,
+            answer.to_json(),
+            timing.parse.as_nanos(),
+            timing.solve.as_nanos()
+        );
+    } else if answer.is_multi_line() {
+        println!("day {} part {} ({:?}) =", day, part, elapsed);
+        println!("{}", answer);
+    } else {
+        println!("day {} part {} ({:?}) = {}", day, part, elapsed, answer);
+    }
+
+    true
+}
+
+/// Parses the input once and runs the requested parts with it.
+/// Returns false when the input could not be loaded or parsed, or a part failed.
+fn run_day(
+    day: u8,
+    part: Option<u8>,
+    solution: &dyn Solver,
+    source: &InputSource,
+    json: bool,
+) -> bool {
+    let input = match source.load(day) {
+        Ok(input) => input,
+        Err(error) => {
+            eprintln!("day {}: {}", day, error);
+            return false;
+        }
+    };
+    let parts = match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+    let timings = match solution.solve_parts_timed(&input, &parts) {
+        Ok(timings) => timings,
+        Err(error) => {
+            eprintln!("day {}: {}", day, error);
+            return false;
+        }
+    };
+    let mut ok = true;
+
+    for (part, timing) in parts.into_iter().zip(timings) {
+        ok = run_part(day, part, timing, json) && ok;
+    }
+
+    ok
+}
+
+/// Benchmarks the requested parts of a day, printing a row per part.
+/// Returns false when the input could not be loaded or parsed.
+fn bench_day(
+    day: u8,
+    part: Option<u8>,
+    solution: &dyn Solver,
+    source: &InputSource,
+    options: &BenchOptions,
+    results: &mut Vec<BenchResult>,
+) -> bool {
+    let input = match source.load(day) {
+        Ok(input) => input,
+        Err(error) => {
+            eprintln!("day {}: {}", day, error);
+            return false;
+        }
+    };
+    let parts = match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+    let mut ok = true;
+
+    for part in parts {
+        match bench_part(day, part, solution, &input, options) {
+            Ok(result) => results.push(result),
+            Err(error) => {
+                eprintln!("day {} part {}: {}", day, part, error);
+                ok = false;
+            }
+        }
+    }
+
+    ok
+}
+
+fn main() {
+    let command = match parse_args(std::env::args().skip(1)) {
+        Ok(command) => command,
+        Err(error) => {
+            eprintln!("{}", error);
+            eprintln!("{}", USAGE);
+            process::exit(1);
+        }
+    };
+
+    match command {
+        Command::Run {
+            day,
+            part,
+            input,
+            json,
+        } => {
+            let source = input.unwrap_or_else(InputSource::from_env);
+            let mut ok = true;
+
+            match day {
+                Some(day) => match find_solution(day) {
+                    Some(solution) => {
+                        ok = run_day(day, part, solution, &source, json);
+                    }
+                    None => {
+                        eprintln!("No solution registered for day {}", day);
+                        ok = false;
+                    }
+                },
+                None => {
+                    for (i, (day, solution)) in SOLUTIONS.iter().enumerate() {
+                        if i > 0 && !json {
+                            println!("-----");
+                        }
+
+                        ok = run_day(*day, part, *solution, &source, json) && ok;
+                    }
+                }
+            }
+
+            if !ok {
+                process::exit(1);
+            }
+        }
+        Command::Verify { answers } => {
+            let path = answers.unwrap_or_else(|| PathBuf::from(DEFAULT_ANSWERS_FILE));
+            let answers = match load_answers(&path) {
+                Ok(answers) => answers,
+                Err(error) => {
+                    eprintln!("{}", error);
+                    process::exit(1);
+                }
+            };
+            let reports = verify(&answers, SOLUTIONS);
+            let summary = Summary::new(&reports);
+
+            for report in &reports {
+                println!("{}", report);
+            }
+
+            println!("-----");
+            println!("{}", summary);
+
+            if summary.failed > 0 {
+                process::exit(1);
+            }
+        }
+        Command::New { day, root } => match scaffold(&root, day) {
+            Ok(paths) => {
+                for path in paths {
+                    println!("{}", path.display());
+                }
+            }
+            Err(error) => {
+                eprintln!("{}", error);
+                process::exit(1);
+            }
+        },
+        Command::Bench {
+            day,
+            part,
+            input,
+            options,
+            save,
+            baseline,
+            threshold,
+        } => {
+            let baseline = baseline.map(|path| {
+                match fs::read_to_string(&path)
+                    .map_err(|error| error.to_string())
+                    .and_then(|content| Baseline::parse(&content))
+                {
+                    Ok(baseline) => baseline,
+                    Err(error) => {
+                        eprintln!("Failed to load baseline {}: {}", path.display(), error);
+                        process::exit(1);
+                    }
+                }
+            });
+            let source = input.unwrap_or_else(InputSource::from_env);
+            let mut results = vec![];
+            let mut ok = true;
+
+            match day {
+                Some(day) => match find_solution(day) {
+                    Some(solution) => {
+                        ok = bench_day(day, part, solution, &source, &options, &mut results);
+                    }
+                    None => {
+                        eprintln!("No solution registered for day {}", day);
+                        ok = false;
+                    }
+                },
+                None => {
+                    for (day, solution) in SOLUTIONS {
+                        ok =
+                            bench_day(*day, part, *solution, &source, &options, &mut results) && ok;
+                    }
+                }
+            }
+
+            println!(
+                "{} runs, {} warmup (min / median / p95)",
+                options.runs, options.warmup
+            );
+
+            let mut regressions = 0;
+
+            for result in &results {
+                let comparison = baseline
+                    .as_ref()
+                    .and_then(|baseline| baseline.compare(result, threshold));
+
+                print!(
+                    "day {:>2} part {}  parse {}  solve {}",
+                    result.day, result.part, result.parse, result.solve
+                );
+
+                match comparison {
+                    Some(comparison) => {
+                        if comparison.regressed {
+                            regressions += 1;
+                        }
+
+                        println!("  {}", comparison);
+                    }
+                    None => println!(),
+                }
+            }
+
+            if baseline.is_some() {
+                println!("-----");
+                println!("{} regressed beyond {}%", regressions, threshold);
+            }
+
+            if let Some(path) = save {
+                if let Err(error) = fs::write(&path, Baseline::from_results(&results).to_string()) {
+                    eprintln!("Failed to save baseline {}: {}", path.display(), error);
+                    ok = false;
+                }
+            }
+
+            if !ok || regressions > 0 {
+                process::exit(1);
+            }
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use aoc_common::Solver;
+
+/// Every solved day, keyed by its day number.
+pub const SOLUTIONS: &[(u8, &dyn Solver)] = &[
+    (1, &aoc_1::Day1),
+    (2, &aoc_2::Day2),
+    (3, &aoc_3::Day3),
+    (4, &aoc_4::Day4),
+    (5, &aoc_5::Day5),
+    (6, &aoc_6::Day6),
+    (7, &aoc_7::Day7),
+    (8, &aoc_8::Day8),
+    (9, &aoc_9::Day9),
+    (10, &aoc_10::Day10),
+    (11, &aoc_11::Day11),
+];
+
+pub fn find_solution(day: u8) -> Option<&'static dyn Solver> {
+    SOLUTIONS
+        .iter()
+        .find(|(number, _)| *number == day)
+        .map(|(_, solution)| *solution)
+}
@@ -0,0 +1,305 @@
+use crate::input::{input_path, DEFAULT_INPUTS_DIR};
+use aoc_common::EXAMPLES_DIR;
+use std::{
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+pub const TEMPLATE_DIR: &str = "crates/day-base";
+
+const TEST_SKELETON: &str = r#"
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Replace with the expected answers from the puzzle description
+    aoc_common::examples!(DayBase, "day-base", {
+        example: { part_1: Answer::Unsolved, part_2: Answer::Unsolved },
+    });
+}
+"#;
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    Exists(PathBuf),
+    Io(PathBuf, io::Error),
+    /// A manifest or source file that couldn't be updated automatically.
+    Register(PathBuf, String),
+}
+
+impl fmt::Display for ScaffoldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScaffoldError::Exists(path) => {
+                write!(f, "{} already exists", path.display())
+            }
+            ScaffoldError::Io(path, error) => {
+                write!(f, "Unable to access {}: {}", path.display(), error)
+            }
+            ScaffoldError::Register(path, message) => {
+                write!(f, "Unable to update {}: {}", path.display(), message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ScaffoldError {}
+
+fn read(path: &Path) -> Result<String, ScaffoldError> {
+    fs::read_to_string(path).map_err(|error| ScaffoldError::Io(path.to_path_buf(), error))
+}
+
+fn write(path: &Path, content: &str) -> Result<(), ScaffoldError> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|error| ScaffoldError::Io(parent.to_path_buf(), error))?;
+    }
+
+    fs::write(path, content).map_err(|error| ScaffoldError::Io(path.to_path_buf(), error))
+}
+
+/// Renames the template's package and struct for the given day.
+pub fn from_template(content: &str, day: u8) -> String {
+    content
+        .replace("aoc_base", &format!("aoc_{}", day))
+        .replace("DayBase", &format!("Day{}", day))
+        .replace("day-base", &format!("day-{}", day))
+}
+
+/// Inserts `line` into a list of per-day lines, keeping them ordered by day.
+/// `day_of` extracts the day from a line, or `None` if it isn't part of the list.
+pub fn insert_day_line(
+    content: &str,
+    day: u8,
+    line: &str,
+    day_of: impl Fn(&str) -> Option<u8>,
+) -> Result<String, String> {
+    let lines = content.lines().collect::<Vec<_>>();
+    let mut last = None;
+    let mut position = None;
+
+    for (index, current) in lines.iter().enumerate() {
+        if let Some(other) = day_of(current.trim()) {
+            if other == day {
+                return Err(format!("day {} is already registered", day));
+            }
+
+            if other > day && position.is_none() {
+                position = Some(index);
+            }
+
+            last = Some(index);
+        }
+    }
+
+    let last = last.ok_or_else(|| String::from("unable to find the list of days"))?;
+    let position = position.unwrap_or(last + 1);
+    let anchor = lines[position.min(last)];
+    let indent = &anchor[..anchor.len() - anchor.trim_start().len()];
+
+    let mut result = lines[..position].join("\n");
+    result.push('\n');
+    result.push_str(indent);
+    result.push_str(line);
+
+    for rest in &lines[position..] {
+        result.push('\n');
+        result.push_str(rest);
+    }
+
+    if content.ends_with('\n') {
+        result.push('\n');
+    }
+
+    Ok(result)
+}
+
+fn number_between(line: &str, prefix: &str, suffix: &str) -> Option<u8> {
+    line.strip_prefix(prefix)?
+        .split_once(suffix)?
+        .0
+        .parse()
+        .ok()
+}
+
+/// The workspace `members` entry, such as `"crates/day-7",`.
+fn workspace_member(line: &str) -> Option<u8> {
+    number_between(line, "\"crates/day-", "\"")
+}
+
+/// The runner dependency, such as `aoc_7 = { path = "../day-7" }`.
+fn runner_dependency(line: &str) -> Option<u8> {
+    number_between(line, "aoc_", " =")
+}
+
+/// The registry entry, such as `(7, &aoc_7::Day7),`.
+fn registry_entry(line: &str) -> Option<u8> {
+    number_between(line, "(", ", &aoc_")
+}
+
+/// Generates `crates/day-N` from the template within the workspace at `root`,
+/// registers it with the workspace and runner, and creates an empty example
+/// fixture and input file. Returns the paths that were created or updated.
+pub fn scaffold(root: &Path, day: u8) -> Result<Vec<PathBuf>, ScaffoldError> {
+    let crate_dir = root.join(format!("crates/day-{}", day));
+
+    if crate_dir.exists() {
+        return Err(ScaffoldError::Exists(crate_dir));
+    }
+
+    let template = root.join(TEMPLATE_DIR);
+    let mut files = vec![
+        (
+            crate_dir.join("Cargo.toml"),
+            from_template(&read(&template.join("Cargo.toml"))?, day),
+        ),
+        (
+            crate_dir.join("src/lib.rs"),
+            from_template(&(read(&template.join("src/lib.rs"))? + TEST_SKELETON), day),
+        ),
+        (
+            root.join(EXAMPLES_DIR)
+                .join(format!("day-{}", day))
+                .join("example.txt"),
+            String::new(),
+        ),
+    ];
+
+    let input = input_path(&root.join(DEFAULT_INPUTS_DIR), day);
+
+    if !input.exists() {
+        files.push((input, String::new()));
+    }
+
+    // Compute every registration before writing, so a failure leaves the tree untouched
+    let registrations = [
+        (
+            root.join("Cargo.toml"),
+            format!("\"crates/day-{}\",", day),
+            workspace_member as fn(&str) -> Option<u8>,
+        ),
+        (
+            root.join("crates/runner/Cargo.toml"),
+            format!("aoc_{0} = {{ path = \"../day-{0}\" }}", day),
+            runner_dependency,
+        ),
+        (
+            root.join("crates/runner/src/registry.rs"),
+            format!("({0}, &aoc_{0}::Day{0}),", day),
+            registry_entry,
+        ),
+    ];
+
+    for (path, line, day_of) in registrations {
+        let content = insert_day_line(&read(&path)?, day, &line, day_of)
+            .map_err(|message| ScaffoldError::Register(path.clone(), message))?;
+
+        files.push((path, content));
+    }
+
+    for (path, content) in &files {
+        write(path, content)?;
+    }
+
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renames_template() {
+        assert_eq!(
+            from_template(
+                "name = \"aoc_base\"\npub struct DayBase;\nexamples!(DayBase, \"day-base\")",
+                12
+            ),
+            "name = \"aoc_12\"\npub struct Day12;\nexamples!(Day12, \"day-12\")"
+        );
+    }
+
+    #[test]
+    fn inserts_in_day_order() {
+        let manifest = "members = [\n    \"crates/common\",\n    \"crates/day-1\",\n    \"crates/day-10\",\n]\n";
+
+        assert_eq!(
+            insert_day_line(manifest, 2, "\"crates/day-2\",", workspace_member).unwrap(),
+            "members = [\n    \"crates/common\",\n    \"crates/day-1\",\n    \"crates/day-2\",\n    \"crates/day-10\",\n]\n"
+        );
+        assert_eq!(
+            insert_day_line(manifest, 12, "\"crates/day-12\",", workspace_member).unwrap(),
+            "members = [\n    \"crates/common\",\n    \"crates/day-1\",\n    \"crates/day-10\",\n    \"crates/day-12\",\n]\n"
+        );
+        assert!(insert_day_line(manifest, 10, "\"crates/day-10\",", workspace_member).is_err());
+        assert!(
+            insert_day_line("[workspace]\n", 1, "\"crates/day-1\",", workspace_member).is_err()
+        );
+    }
+
+    #[test]
+    fn recognizes_registrations() {
+        assert_eq!(workspace_member("\"crates/day-7\","), Some(7));
+        assert_eq!(workspace_member("\"crates/day-base\","), None);
+        assert_eq!(
+            runner_dependency("aoc_11 = { path = \"../day-11\" }"),
+            Some(11)
+        );
+        assert_eq!(
+            runner_dependency("aoc_common = { path = \"../common\" }"),
+            None
+        );
+        assert_eq!(registry_entry("(9, &aoc_9::Day9),"), Some(9));
+    }
+
+    #[test]
+    fn scaffolds_workspace() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+
+        write(
+            &root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\n    \"crates/day-1\",\n]\n",
+        )
+        .unwrap();
+        write(
+            &root.join("crates/runner/Cargo.toml"),
+            "[dependencies]\naoc_1 = { path = \"../day-1\" }\n",
+        )
+        .unwrap();
+        write(
+            &root.join("crates/runner/src/registry.rs"),
+            "pub const SOLUTIONS: &[(u8, &dyn Solver)] = &[\n    (1, &aoc_1::Day1),\n];\n",
+        )
+        .unwrap();
+        write(
+            &root.join("crates/day-base/Cargo.toml"),
+            "[package]\nname = \"aoc_base\"\n",
+        )
+        .unwrap();
+        write(
+            &root.join("crates/day-base/src/lib.rs"),
+            "pub struct DayBase;\n",
+        )
+        .unwrap();
+
+        let created = scaffold(&root, 2).unwrap();
+
+        assert_eq!(created.len(), 7);
+        assert_eq!(
+            read(&root.join("crates/day-2/Cargo.toml")).unwrap(),
+            "[package]\nname = \"aoc_2\"\n"
+        );
+        assert!(read(&root.join("crates/day-2/src/lib.rs"))
+            .unwrap()
+            .contains("examples!(Day2, \"day-2\""));
+        assert!(root.join("examples/day-2/example.txt").is_file());
+        assert!(root.join("inputs/day-02.txt").is_file());
+        assert!(read(&root.join("crates/runner/src/registry.rs"))
+            .unwrap()
+            .contains("    (2, &aoc_2::Day2),\n"));
+        assert!(matches!(scaffold(&root, 2), Err(ScaffoldError::Exists(_))));
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
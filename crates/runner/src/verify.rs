@@ -0,0 +1,240 @@
+use crate::{answers::KnownAnswer, input::InputSource};
+use aoc_common::{Answer, Solver};
+use std::{fmt, path::PathBuf};
+
+#[derive(Debug, PartialEq)]
+pub enum Outcome {
+    Pass,
+    Fail {
+        expected: Answer,
+        actual: Answer,
+    },
+    /// The input could not be parsed, or the day has no solution.
+    Error(String),
+    /// No expected answer is known, or its input file doesn't exist.
+    Missing(String),
+}
+
+impl Outcome {
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Outcome::Fail { .. } | Outcome::Error(_))
+    }
+}
+
+#[derive(Debug)]
+pub struct Report {
+    pub day: u8,
+    pub part: u8,
+    pub input: Option<PathBuf>,
+    pub outcome: Outcome,
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = match &self.outcome {
+            Outcome::Pass => "PASS",
+            Outcome::Fail { .. } => "FAIL",
+            Outcome::Error(_) => "ERROR",
+            Outcome::Missing(_) => "MISSING",
+        };
+
+        write!(f, "{:<7} day {} part {}", status, self.day, self.part)?;
+
+        if let Some(input) = &self.input {
+            write!(f, " ({})", input.display())?;
+        }
+
+        match &self.outcome {
+            Outcome::Pass => Ok(()),
+            Outcome::Fail { expected, actual } => {
+                if expected.is_multi_line() || actual.is_multi_line() {
+                    write!(f, ": expected\n{}\nbut got\n{}", expected, actual)
+                } else {
+                    write!(f, ": expected {} but got {}", expected, actual)
+                }
+            }
+            Outcome::Error(message) | Outcome::Missing(message) => write!(f, ": {}", message),
+        }
+    }
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Summary {
+    pub passed: usize,
+    pub failed: usize,
+    pub missing: usize,
+}
+
+impl Summary {
+    pub fn new(reports: &[Report]) -> Self {
+        let mut summary = Summary::default();
+
+        for report in reports {
+            match report.outcome {
+                Outcome::Pass => summary.passed += 1,
+                Outcome::Fail { .. } | Outcome::Error(_) => summary.failed += 1,
+                Outcome::Missing(_) => summary.missing += 1,
+            }
+        }
+
+        summary
+    }
+}
+
+impl fmt::Display for Summary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} passed, {} failed, {} missing",
+            self.passed, self.failed, self.missing
+        )
+    }
+}
+
+fn check(solution: &dyn Solver, known: &KnownAnswer) -> Outcome {
+    let input = match InputSource::File(known.input.clone()).load(known.day) {
+        Ok(input) => input,
+        Err(error) => return Outcome::Missing(error.to_string()),
+    };
+
+    match solution.solve(&input, known.part) {
+        Ok(actual) if actual == known.expected => Outcome::Pass,
+        Ok(actual) => Outcome::Fail {
+            expected: known.expected.clone(),
+            actual,
+        },
+        Err(error) => Outcome::Error(error.to_string()),
+    }
+}
+
+/// Runs every part of every registered solution against its known answers.
+/// Parts without a known answer are reported as missing.
+pub fn verify(answers: &[KnownAnswer], solutions: &[(u8, &dyn Solver)]) -> Vec<Report> {
+    let mut reports = vec![];
+
+    for (day, solution) in solutions {
+        for part in 1..=2 {
+            let mut found = false;
+
+            for known in answers
+                .iter()
+                .filter(|known| known.day == *day && known.part == part)
+            {
+                found = true;
+                reports.push(Report {
+                    day: *day,
+                    part,
+                    input: Some(known.input.clone()),
+                    outcome: check(*solution, known),
+                });
+            }
+
+            if !found {
+                reports.push(Report {
+                    day: *day,
+                    part,
+                    input: None,
+                    outcome: Outcome::Missing(String::from("no expected answer")),
+                });
+            }
+        }
+    }
+
+    for known in answers {
+        if !solutions.iter().any(|(day, _)| *day == known.day) {
+            reports.push(Report {
+                day: known.day,
+                part: known.part,
+                input: Some(known.input.clone()),
+                outcome: Outcome::Error(String::from("no solution registered")),
+            });
+        }
+    }
+
+    reports
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{ParseError, Solution, SolveError};
+
+    struct Length;
+
+    impl Solution for Length {
+        type Input<'a> = &'a str;
+
+        fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+            Ok(input.trim())
+        }
+
+        fn part_1(&self, input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+            Ok(input.len().into())
+        }
+
+        fn part_2(&self, _input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+            Err(SolveError::new("always fails"))
+        }
+    }
+
+    #[test]
+    fn reports_outcomes() {
+        let input = std::env::temp_dir().join("aoc-verify-test.txt");
+
+        std::fs::write(&input, "abcd\n").unwrap();
+
+        let known = |part, expected: i128| KnownAnswer {
+            day: 1,
+            part,
+            input: input.clone(),
+            expected: Answer::Integer(expected),
+        };
+        let answers = vec![known(1, 4), known(1, 5), known(2, 0)];
+        let solutions: &[(u8, &dyn Solver)] = &[(1, &Length), (2, &Length)];
+        let reports = verify(&answers, solutions);
+        let outcomes = reports
+            .iter()
+            .map(|report| (report.day, report.part, &report.outcome))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            outcomes,
+            vec![
+                (1, 1, &Outcome::Pass),
+                (
+                    1,
+                    1,
+                    &Outcome::Fail {
+                        expected: Answer::Integer(5),
+                        actual: Answer::Integer(4)
+                    }
+                ),
+                (1, 2, &Outcome::Error(String::from("always fails"))),
+                (2, 1, &Outcome::Missing(String::from("no expected answer"))),
+                (2, 2, &Outcome::Missing(String::from("no expected answer"))),
+            ]
+        );
+        assert_eq!(
+            Summary::new(&reports),
+            Summary {
+                passed: 1,
+                failed: 2,
+                missing: 2
+            }
+        );
+    }
+
+    #[test]
+    fn missing_input() {
+        let answers = vec![KnownAnswer {
+            day: 1,
+            part: 1,
+            input: PathBuf::from("does-not-exist.txt"),
+            expected: Answer::Integer(1),
+        }];
+        let reports = verify(&answers, &[(1, &Length)]);
+
+        assert!(matches!(reports[0].outcome, Outcome::Missing(_)));
+        assert!(!reports[0].outcome.is_failure());
+    }
+}
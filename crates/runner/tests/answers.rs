@@ -0,0 +1,21 @@
+use aoc::{
+    answers::{load_answers, DEFAULT_ANSWERS_FILE},
+    registry::SOLUTIONS,
+    verify::verify,
+};
+use std::path::Path;
+
+#[test]
+fn known_answers() {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("../..")
+        .join(DEFAULT_ANSWERS_FILE);
+    let answers = load_answers(&path).unwrap();
+    let failures = verify(&answers, SOLUTIONS)
+        .into_iter()
+        .filter(|report| report.outcome.is_failure())
+        .map(|report| report.to_string())
+        .collect::<Vec<_>>();
+
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}